@@ -0,0 +1,158 @@
+//! Stored contract calling the bridge pool on its own behalf, used by the integration tests to
+//! drive the bridge pool from a contract package instead of an account.
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::{string::String, vec, vec::Vec};
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{
+    runtime_args, ApiError, CLType, CLTyped, ContractPackageHash, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Key, Parameter, RuntimeArgs, U256,
+};
+
+const BRIDGE_CALLER_CONTRACT_KEY_NAME: &str = "bridge_caller_contract";
+const BRIDGE_CALLER_CONTRACT_PACKAGE_KEY_NAME: &str = "bridge_caller_contract_package_hash";
+const BRIDGE_CALLER_ACCESS_KEY_NAME: &str = "bridge_caller_access_uref";
+
+const APPROVE: &str = "approve";
+const ADD_LIQUIDITY: &str = "add_liquidity";
+const WITHDRAW_SIGNED: &str = "withdraw_signed";
+
+const TOKEN: &str = "token";
+const SPENDER: &str = "spender";
+const BRIDGE_POOL: &str = "bridge_pool";
+const AMOUNT: &str = "amount";
+const TOKEN_ADDRESS: &str = "token_address";
+const BRIDGE_POOL_CONTRACT_PACKAGE_HASH: &str = "bridge_pool_contract_package_hash";
+const PAYEE: &str = "payee";
+const CHAIN_ID: &str = "chain_id";
+const SALT: &str = "salt";
+const SIGNATURES: &str = "signatures";
+const TOKEN_RECIPIENT: &str = "token_recipient";
+const CALLER: &str = "caller";
+const EXPIRY: &str = "expiry";
+
+/// Returns the contract package hash behind a `Key::Hash` argument.
+fn get_contract_package_hash_arg(name: &str) -> ContractPackageHash {
+    match runtime::get_named_arg::<Key>(name) {
+        Key::Hash(hash) => ContractPackageHash::new(hash),
+        _ => runtime::revert(ApiError::UnexpectedKeyVariant),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let token = get_contract_package_hash_arg(TOKEN);
+    let spender = runtime::get_named_arg::<Key>(SPENDER);
+    let amount = runtime::get_named_arg::<U256>(AMOUNT);
+
+    runtime::call_versioned_contract::<()>(
+        token,
+        None,
+        APPROVE,
+        runtime_args! {
+            SPENDER => spender,
+            AMOUNT => amount,
+        },
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn add_liquidity() {
+    let bridge_pool = get_contract_package_hash_arg(BRIDGE_POOL);
+
+    runtime::call_versioned_contract::<()>(
+        bridge_pool,
+        None,
+        ADD_LIQUIDITY,
+        runtime_args! {
+            AMOUNT => runtime::get_named_arg::<U256>(AMOUNT),
+            TOKEN_ADDRESS => runtime::get_named_arg::<String>(TOKEN_ADDRESS),
+            BRIDGE_POOL_CONTRACT_PACKAGE_HASH => bridge_pool.to_formatted_string(),
+        },
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw_signed() {
+    let bridge_pool = get_contract_package_hash_arg(BRIDGE_POOL);
+
+    runtime::call_versioned_contract::<()>(
+        bridge_pool,
+        None,
+        WITHDRAW_SIGNED,
+        runtime_args! {
+            TOKEN_ADDRESS => runtime::get_named_arg::<String>(TOKEN_ADDRESS),
+            PAYEE => runtime::get_named_arg::<String>(PAYEE),
+            AMOUNT => runtime::get_named_arg::<U256>(AMOUNT),
+            CHAIN_ID => runtime::get_named_arg::<u64>(CHAIN_ID),
+            SALT => runtime::get_named_arg::<String>(SALT),
+            SIGNATURES => runtime::get_named_arg::<Vec<String>>(SIGNATURES),
+            TOKEN_RECIPIENT => runtime::get_named_arg::<String>(TOKEN_RECIPIENT),
+            CALLER => runtime::get_named_arg::<String>(CALLER),
+            EXPIRY => runtime::get_named_arg::<u64>(EXPIRY),
+        },
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+
+    entry_points.add_entry_point(EntryPoint::new(
+        APPROVE,
+        vec![
+            Parameter::new(TOKEN, Key::cl_type()),
+            Parameter::new(SPENDER, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        ADD_LIQUIDITY,
+        vec![
+            Parameter::new(BRIDGE_POOL, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        WITHDRAW_SIGNED,
+        vec![
+            Parameter::new(BRIDGE_POOL, Key::cl_type()),
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(PAYEE, String::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(CHAIN_ID, u64::cl_type()),
+            Parameter::new(SALT, String::cl_type()),
+            Parameter::new(SIGNATURES, Vec::<String>::cl_type()),
+            Parameter::new(TOKEN_RECIPIENT, String::cl_type()),
+            Parameter::new(CALLER, String::cl_type()),
+            Parameter::new(EXPIRY, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let (contract_hash, _version) = storage::new_contract(
+        entry_points,
+        None,
+        Some(String::from(BRIDGE_CALLER_CONTRACT_PACKAGE_KEY_NAME)),
+        Some(String::from(BRIDGE_CALLER_ACCESS_KEY_NAME)),
+    );
+    runtime::put_key(BRIDGE_CALLER_CONTRACT_KEY_NAME, contract_hash.into());
+}
@@ -38,6 +38,17 @@ impl Address {
             None
         }
     }
+
+    /// Parses a formatted account hash (`account-hash-<hex>`) or contract package hash
+    /// (`contract-package-wasm<hex>`).
+    pub fn from_formatted_str(input: &str) -> Result<Address, crate::error::Error> {
+        if let Ok(account_hash) = AccountHash::from_formatted_str(input) {
+            return Ok(Self::Account(account_hash));
+        }
+        ContractPackageHash::from_formatted_str(input)
+            .map(Self::ContractPackage)
+            .map_err(|_| crate::error::Error::InvalidPayee)
+    }
 }
 
 impl From<ContractPackageHash> for Address {
@@ -52,6 +63,20 @@ impl From<AccountHash> for Address {
     }
 }
 
+impl TryFrom<Key> for Address {
+    type Error = crate::error::Error;
+
+    fn try_from(key: Key) -> Result<Self, Self::Error> {
+        match key {
+            Key::Account(account_hash) => Ok(Self::Account(account_hash)),
+            Key::Hash(raw_contract_package_hash) => Ok(Self::ContractPackage(
+                ContractPackageHash::new(raw_contract_package_hash),
+            )),
+            _ => Err(Self::Error::NeitherAccountHashNorNeitherContractPackageHash),
+        }
+    }
+}
+
 impl TryInto<String> for Address {
     type Error = crate::error::Error;
 
@@ -1,6 +1,13 @@
 use crate::address::Address;
-use crate::consts::{AMOUNT, ERC20_ENTRY_POINT_TRANSFER, RECIPIENT};
+use crate::consts::{
+    ENTRY_POINT_ADD_LIQUIDITY, ENTRY_POINT_CLAIM_REWARDS, ENTRY_POINT_REMOVE_LIQUIDITY,
+    ENTRY_POINT_SWAP, ENTRY_POINT_WITHDRAW_SIGNED, NATIVE_TOKEN, WITHDRAWAL_SUCCEEDED,
+    WITHDRAW_DOMAIN_VERSION,
+};
 use crate::detail;
+use crate::pending_withdrawal::PendingWithdrawal;
+use crate::signed_withdrawal::SignedWithdrawal;
+use crate::token_standard::TokenStandard;
 use crate::{
     data::{self, BridgePool},
     error::Error,
@@ -10,18 +17,17 @@ use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use casper_contract::contract_api::runtime;
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
-use casper_types::RuntimeArgs;
-use casper_types::{runtime_args, ContractPackageHash, U256};
-use contract_utils::keccak::{keccak256, keccak256_hash};
-use contract_utils::{ContractContext, ContractStorage, Dict};
+use casper_types::{ContractPackageHash, Key, URef, U256};
+use contract_utils::keccak::{self, keccak256_hash};
+use contract_utils::{ContractContext, ContractStorage};
 use k256::ecdsa::{
     recoverable::Signature as RecoverableSignature, signature::Signature as NonRecoverableSignature,
 };
 use secp256k1::{Message, Secp256k1};
 
 pub trait BridgePoolContract<Storage: ContractStorage>: ContractContext<Storage> {
-    fn init(&mut self) {
-        BridgePool::init();
+    fn init(&mut self, chain_name: String) {
+        BridgePool::init(chain_name);
     }
 
     fn emit(&mut self, event: BridgePoolEvent) {
@@ -42,6 +48,17 @@ pub trait BridgePoolContract<Storage: ContractStorage>: ContractContext<Storage>
             .get_liquidity_added_by_client(token_contract_package_hash, client_address)
     }
 
+    // outer function to get liquidity already in pool of any account or contract package
+    fn get_liquidity_of(&mut self, token_address: String, owner: Key) -> Result<U256, Error> {
+        let token_contract_package_hash =
+            ContractPackageHash::from_formatted_str(token_address.as_str())
+                .map_err(|_| Error::NotContractPackageHash)?;
+        let owner = Address::try_from(owner)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.get_liquidity_added_by_client(token_contract_package_hash, owner)
+    }
+
     // outer function to add liquidity to the pool
     fn add_liquidity(
         &mut self,
@@ -62,11 +79,13 @@ pub trait BridgePoolContract<Storage: ContractStorage>: ContractContext<Storage>
             .unwrap_or_revert_with(Error::ImmediateCallerFail);
 
         let bridge_pool_instance = BridgePool::instance();
-        bridge_pool_instance.add_liquidity(
+        bridge_pool_instance.check_not_paused(ENTRY_POINT_ADD_LIQUIDITY)?;
+        let amount = bridge_pool_instance.add_liquidity(
             bridge_pool_contract_package_hash,
             token_contract_package_hash,
             client_address,
             amount,
+            None,
         )?;
 
         self.emit(BridgePoolEvent::BridgeLiquidityAdded {
@@ -77,6 +96,60 @@ pub trait BridgePoolContract<Storage: ContractStorage>: ContractContext<Storage>
         Ok(())
     }
 
+    // outer function to add native CSPR from a purse of the caller to the pool
+    fn add_liquidity_native(&mut self, amount: U256, purse: URef) -> Result<(), Error> {
+        let client_address = detail::get_immediate_caller_address()
+            .unwrap_or_revert_with(Error::ImmediateCallerFail);
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.check_not_paused(ENTRY_POINT_ADD_LIQUIDITY)?;
+        let amount = bridge_pool_instance.add_liquidity(
+            data::contract_package_hash(),
+            NATIVE_TOKEN,
+            client_address,
+            amount,
+            Some(purse),
+        )?;
+
+        self.emit(BridgePoolEvent::BridgeLiquidityAdded {
+            actor: client_address,
+            token: NATIVE_TOKEN,
+            amount,
+        });
+        Ok(())
+    }
+
+    // outer function to approve the pool with a permit signed by the caller and add liquidity
+    // in the same deploy
+    fn add_liquidity_with_permit(
+        &mut self,
+        amount: U256,
+        token_address: String,
+        deadline: u64,
+        signature: String,
+    ) -> Result<(), Error> {
+        let token_contract_package_hash =
+            ContractPackageHash::from_formatted_str(token_address.as_str())
+                .map_err(|_| Error::NotContractPackageHash)?;
+
+        let client_address = detail::get_immediate_caller_address()
+            .unwrap_or_revert_with(Error::ImmediateCallerFail);
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.permit(
+            token_contract_package_hash,
+            client_address,
+            amount,
+            deadline,
+            signature,
+        )?;
+        self.add_liquidity(
+            amount,
+            token_address,
+            data::contract_package_hash().to_formatted_string(),
+        )
+    }
+
     // outer function to remove liquidity from the pool
     fn remove_liquidity(&mut self, amount: U256, token_address: String) -> Result<(), Error> {
         let token_contract_package_hash =
@@ -87,6 +160,7 @@ pub trait BridgePoolContract<Storage: ContractStorage>: ContractContext<Storage>
             .unwrap_or_revert_with(Error::ImmediateCallerFail);
 
         let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.check_not_paused(ENTRY_POINT_REMOVE_LIQUIDITY)?;
         bridge_pool_instance.remove_liquidity(
             token_contract_package_hash,
             client_address,
@@ -117,7 +191,9 @@ pub trait BridgePoolContract<Storage: ContractStorage>: ContractContext<Storage>
             .map_err(|_| Error::NotContractPackageHash)?;
 
         let bridge_pool_instance = BridgePool::instance();
-        bridge_pool_instance.swap(actor, token, target_token, amount, target_network)?;
+        bridge_pool_instance.check_not_paused(ENTRY_POINT_SWAP)?;
+        let (amount, fee) =
+            bridge_pool_instance.swap(actor, token, target_token, amount, target_network, None)?;
 
         self.emit(BridgePoolEvent::BridgeSwap {
             actor,
@@ -125,6 +201,71 @@ pub trait BridgePoolContract<Storage: ContractStorage>: ContractContext<Storage>
             target_network,
             target_address,
             amount,
+            fee,
+        });
+        Ok(())
+    }
+
+    // outer function to approve the pool with a permit signed by the caller and swap in the
+    // same deploy
+    #[allow(clippy::too_many_arguments)]
+    fn swap_with_permit(
+        &mut self,
+        token_address: String,
+        amount: U256,
+        target_network: U256,
+        target_token: String,
+        target_address: String,
+        deadline: u64,
+        signature: String,
+    ) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let actor = detail::get_immediate_caller_address()
+            .unwrap_or_revert_with(Error::ImmediateCallerFail);
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.permit(token, actor, amount, deadline, signature)?;
+        self.swap(
+            token_address,
+            amount,
+            target_network,
+            target_token,
+            target_address,
+        )
+    }
+
+    // outer function to swap native CSPR from a purse of the caller
+    fn swap_native(
+        &mut self,
+        amount: U256,
+        target_network: U256,
+        target_token: String,
+        target_address: String,
+        purse: URef,
+    ) -> Result<(), Error> {
+        let actor = detail::get_immediate_caller_address()
+            .unwrap_or_revert_with(Error::ImmediateCallerFail);
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.check_not_paused(ENTRY_POINT_SWAP)?;
+        let (amount, fee) = bridge_pool_instance.swap(
+            actor,
+            NATIVE_TOKEN,
+            target_token,
+            amount,
+            target_network,
+            Some(purse),
+        )?;
+
+        self.emit(BridgePoolEvent::BridgeSwap {
+            actor,
+            token: NATIVE_TOKEN,
+            target_network,
+            target_address,
+            amount,
+            fee,
         });
         Ok(())
     }
@@ -145,6 +286,44 @@ pub trait BridgePoolContract<Storage: ContractStorage>: ContractContext<Storage>
         Ok(())
     }
 
+    // outer function to disallow target
+    fn disallow_target(
+        &mut self,
+        token_address: String,
+        target_network: U256,
+    ) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.disallow_target(token, target_network)?;
+        self.emit(BridgePoolEvent::TargetDisallowed {
+            token,
+            target_network,
+        });
+        Ok(())
+    }
+
+    // outer function to update target
+    fn update_target(
+        &mut self,
+        token_address: String,
+        target_network: U256,
+        target_token: String,
+    ) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.update_target(token, target_token.clone(), target_network)?;
+        self.emit(BridgePoolEvent::TargetUpdated {
+            token,
+            target_network,
+            target_token,
+        });
+        Ok(())
+    }
+
     // outer function to withdraw liquidity from the pool securely
     #[allow(clippy::too_many_arguments)]
     #[inline(always)]
@@ -156,123 +335,383 @@ pub trait BridgePoolContract<Storage: ContractStorage>: ContractContext<Storage>
         chain_id: u64,
         salt: String,
         caller: String,
-        signature: String,
+        signatures: Vec<String>,
+        receiver: String,
+        expiry: u64,
+    ) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        self.withdraw_signed_token(
+            token, payee, amount, chain_id, salt, caller, signatures, receiver, expiry,
+        )
+    }
+
+    // outer function to withdraw native CSPR from the pool purse securely
+    #[allow(clippy::too_many_arguments)]
+    fn withdraw_signed_native(
+        &mut self,
+        payee: String,
+        amount: U256,
+        chain_id: u64,
+        salt: String,
+        caller: String,
+        signatures: Vec<String>,
+        receiver: String,
+        expiry: u64,
+    ) -> Result<(), Error> {
+        self.withdraw_signed_token(
+            NATIVE_TOKEN,
+            payee,
+            amount,
+            chain_id,
+            salt,
+            caller,
+            signatures,
+            receiver,
+            expiry,
+        )
+    }
+
+    // outer function to withdraw several tokens securely in one deploy, returns the result of
+    // every withdrawal, WITHDRAWAL_SUCCEEDED or the error code it failed with
+    fn withdraw_signed_batch(
+        &mut self,
+        withdrawals: Vec<SignedWithdrawal>,
+        revert_on_failure: bool,
+    ) -> Result<Vec<u16>, Error> {
+        let actor = detail::get_immediate_caller_address()
+            .unwrap_or_revert_with(Error::ImmediateCallerFail);
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.check_not_paused(ENTRY_POINT_WITHDRAW_SIGNED)?;
+
+        let mut results: Vec<u16> = Vec::new();
+        for withdrawal in withdrawals {
+            let amount = withdrawal.amount;
+            let authorization =
+                ContractPackageHash::from_formatted_str(withdrawal.token_address.as_str())
+                    .map_err(|_| Error::NotContractPackageHash)
+                    .and_then(|token| {
+                        self.authorize_withdrawal(
+                            actor,
+                            token,
+                            withdrawal.payee,
+                            amount,
+                            withdrawal.chain_id,
+                            withdrawal.salt,
+                            withdrawal.token_recipient,
+                            withdrawal.signatures,
+                            withdrawal.caller.clone(),
+                            withdrawal.expiry,
+                        )
+                        .map(|(message_hash, signers)| (token, message_hash, signers))
+                    });
+            let result = authorization.and_then(|(token, message_hash, signers)| {
+                self.complete_withdrawal(
+                    actor,
+                    token,
+                    amount,
+                    withdrawal.caller,
+                    message_hash,
+                    signers,
+                )
+            });
+            match result {
+                Ok(()) => results.push(WITHDRAWAL_SUCCEEDED),
+                Err(error) if !revert_on_failure => results.push(error as u16),
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(results)
+    }
+
+    // outer function for any account to submit a withdrawal signed for `payee`, who is paid the
+    // amount less the relayer fee, which goes to the submitter
+    #[allow(clippy::too_many_arguments)]
+    fn withdraw_signed_relayed(
+        &mut self,
+        token_address: String,
+        payee: String,
+        amount: U256,
+        relayer_fee: U256,
+        chain_id: u64,
+        salt: String,
+        signatures: Vec<String>,
+        expiry: u64,
+    ) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let relayer = detail::get_immediate_caller_address()
+            .unwrap_or_revert_with(Error::ImmediateCallerFail);
+
+        let recipient = Address::from_formatted_str(&payee)?;
+
+        if relayer_fee > amount {
+            return Err(Error::RelayerFeeTooHigh);
+        }
+
+        if u64::from(runtime::get_blocktime()) > expiry {
+            return Err(Error::AuthorizationExpired);
+        }
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.check_not_paused(ENTRY_POINT_WITHDRAW_SIGNED)?;
+
+        let domain_separator = keccak::domain_separator(
+            data::contract_package_hash().value(),
+            &bridge_pool_instance.get_chain_name()?,
+            WITHDRAW_DOMAIN_VERSION,
+        );
+
+        let message_hash = keccak::relayed_message_hash(
+            domain_separator,
+            token.value(),
+            payee.clone(),
+            amount,
+            relayer_fee,
+            chain_id,
+            decode_salt(salt)?,
+            expiry,
+        );
+
+        bridge_pool_instance.check_payout(token, recipient, amount)?;
+        let signers = self.authorize_message_hash(token, amount, &message_hash, signatures)?;
+
+        // a large withdrawal is queued for the payee, any account can claim it once its time lock
+        // has passed and is paid the relayer fee for it
+        if let Some(release_time) = bridge_pool_instance.get_release_time(token, amount) {
+            bridge_pool_instance.queue_withdrawal(
+                &message_hash,
+                PendingWithdrawal {
+                    token,
+                    recipient,
+                    amount,
+                    release_time,
+                    relayer_fee: Some(relayer_fee),
+                },
+            );
+            self.emit(BridgePoolEvent::WithdrawalQueued {
+                message_hash,
+                signers,
+                recipient,
+                token,
+                amount,
+                release_time,
+            });
+            return Ok(());
+        }
+
+        bridge_pool_instance.pay_relayed_withdrawal(
+            token,
+            recipient,
+            relayer,
+            amount,
+            relayer_fee,
+        )?;
+
+        self.emit(BridgePoolEvent::TransferBySignature {
+            signers,
+            receiver: payee,
+            token,
+            amount: amount - relayer_fee,
+        });
+        if !relayer_fee.is_zero() {
+            self.emit(BridgePoolEvent::RelayerFeePaid {
+                message_hash,
+                relayer,
+                token,
+                amount: relayer_fee,
+            });
+        }
+        Ok(())
+    }
+
+    // function to check the signatures of a withdrawal and pay it out, or queue it when time locked
+    #[allow(clippy::too_many_arguments)]
+    #[inline(always)]
+    fn withdraw_signed_token(
+        &mut self,
+        token: ContractPackageHash,
+        payee: String,
+        amount: U256,
+        chain_id: u64,
+        salt: String,
+        caller: String,
+        signatures: Vec<String>,
         receiver: String,
+        expiry: u64,
     ) -> Result<(), Error> {
         let actor = detail::get_immediate_caller_address()
             .unwrap_or_revert_with(Error::ImmediateCallerFail);
 
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.check_not_paused(ENTRY_POINT_WITHDRAW_SIGNED)?;
+
+        let (message_hash, signers) = self.authorize_withdrawal(
+            actor,
+            token,
+            payee,
+            amount,
+            chain_id,
+            salt,
+            caller,
+            signatures,
+            receiver.clone(),
+            expiry,
+        )?;
+        self.complete_withdrawal(actor, token, amount, receiver, message_hash, signers)
+    }
+
+    // function to check the signatures of a withdrawal and that it can be paid out, marks its
+    // message hash as used and consumes the rate limit of the token. Nothing is written unless
+    // every check passes, so a failed withdrawal can be skipped within a batch
+    #[allow(clippy::too_many_arguments)]
+    fn authorize_withdrawal(
+        &mut self,
+        actor: Address,
+        token: ContractPackageHash,
+        payee: String,
+        amount: U256,
+        chain_id: u64,
+        salt: String,
+        caller: String,
+        signatures: Vec<String>,
+        receiver: String,
+        expiry: u64,
+    ) -> Result<(String, Vec<String>), Error> {
         let client_address_string: String = actor.try_into()?;
 
         if receiver != client_address_string {
             return Err(Error::WrongCaller);
         }
 
-        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
-            .map_err(|_| Error::NotContractPackageHash)?;
+        if u64::from(runtime::get_blocktime()) > expiry {
+            return Err(Error::AuthorizationExpired);
+        }
 
         let bridge_pool_instance = BridgePool::instance();
 
-        let signature = hex::decode(signature).unwrap();
+        let domain_separator = keccak::domain_separator(
+            data::contract_package_hash().value(),
+            &bridge_pool_instance.get_chain_name()?,
+            WITHDRAW_DOMAIN_VERSION,
+        );
 
-        let salt: [u8; 32] = hex::decode(salt)
-            .map_err(|_| Error::SaltHexFail)?
-            .try_into()
-            .map_err(|_| Error::SaltWrongSize)?;
+        let message_hash = keccak::message_hash(
+            domain_separator,
+            token.value(),
+            payee,
+            amount,
+            receiver,
+            chain_id,
+            decode_salt(salt)?,
+            caller,
+            expiry,
+        );
 
-        let message_hash = hex::encode(keccak256(
-            hex::encode(keccak256(
-                &[
-                    token.to_formatted_string().as_bytes(),
-                    payee.as_bytes(),
-                    amount.to_string().as_bytes(),
-                    caller.as_bytes(),
-                    receiver.as_bytes(),
-                    &chain_id.to_be_bytes(),
-                    &salt,
-                ]
-                .concat()[..],
-            ))
-            .as_bytes(),
-        ));
+        // time locked withdrawals are checked again when they are claimed
+        bridge_pool_instance.check_payout(token, actor, amount)?;
 
-        let signature_rec = if signature.len() == 65 {
-            RecoverableSignature::from_bytes(&signature[..])
-                .map_err(|_| Error::RecoverableSignatureTryFromFail)?
-        } else {
-            NonRecoverableSignature::from_bytes(&signature[..])
-                .map_err(|_| Error::NonRecoverableSignatureTryFromFail)?
-        };
-
-        let hash =
-            &hex::decode(message_hash.clone()).map_err(|_| Error::MessageHashHexDecodingFail)?[..];
-        let sig = &signature_rec;
-
-        let s = Secp256k1::new();
-        let msg = Message::from_slice(hash).unwrap();
-        let mut sig_compact: Vec<u8> = sig.r().to_bytes().to_vec();
-        sig_compact.extend(&sig.s().to_bytes().to_vec());
-        let id_u8: u8 = From::from(sig.recovery_id());
-        let sig_v = secp256k1::ecdsa::RecoveryId::from_i32(id_u8 as i32).unwrap();
-        let rec_sig =
-            secp256k1::ecdsa::RecoverableSignature::from_compact(&sig_compact, sig_v).unwrap();
-        let pub_key = s.recover_ecdsa(&msg, &rec_sig).unwrap();
-        let public_key = Vec::from(&keccak256_hash(&pub_key.serialize_uncompressed()[1..])[12..]);
-
-        if bridge_pool_instance
-            .used_hashes_dict
-            .get::<bool>(message_hash.as_str())
-            .is_some()
+        let signers = self.authorize_message_hash(token, amount, &message_hash, signatures)?;
+        Ok((message_hash, signers))
+    }
+
+    // function to check that a signed message hash is unused and signed by enough distinct
+    // registered signers, then marks it as used and consumes the rate limit of the token.
+    // Nothing is written unless every check passes
+    fn authorize_message_hash(
+        &mut self,
+        token: ContractPackageHash,
+        amount: U256,
+        message_hash: &str,
+        signatures: Vec<String>,
+    ) -> Result<Vec<String>, Error> {
+        let bridge_pool_instance = BridgePool::instance();
+
+        let hash = &hex::decode(message_hash).map_err(|_| Error::MessageHashHexDecodingFail)?[..];
+
+        // hashes used before the dictionaries were keyed on typed keys are still honoured
+        if bridge_pool_instance.used_hashes_dict.contains(message_hash)
+            || bridge_pool_instance
+                .used_hashes_dict
+                .as_dict()
+                .get::<bool>(message_hash)
+                .is_some()
         {
             return Err(Error::MessageAlreadyUsed);
-        } else {
-            bridge_pool_instance
-                .used_hashes_dict
-                .set(message_hash.as_str(), true);
         }
 
-        let signer = hex::encode(public_key);
+        // every signature has to come from a distinct registered signer
+        let mut signers: Vec<String> = Vec::new();
+        for signature in signatures {
+            let signer = recover_signer(hash, &signature)?;
+            if signers.contains(&signer) {
+                return Err(Error::DuplicateSigner);
+            }
+            if !bridge_pool_instance
+                .signers_dict
+                .get(&signer)
+                .ok_or(Error::NoValueInSignersDict)?
+            {
+                return Err(Error::InvalidSigner);
+            }
+            signers.push(signer);
+        }
 
-        if !bridge_pool_instance
-            .signers_dict
-            .get::<bool>(&signer)
-            .ok_or(Error::NoValueInSignersDict)?
-        {
-            return Err(Error::InvalidSigner);
+        if (signers.len() as u32) < bridge_pool_instance.get_threshold() {
+            return Err(Error::NotEnoughSignatures);
         }
 
-        runtime::call_versioned_contract::<()>(
-            token,
-            None,
-            ERC20_ENTRY_POINT_TRANSFER,
-            runtime_args! {
-                RECIPIENT => actor,
-                AMOUNT => amount
-            },
-        );
+        bridge_pool_instance.consume_rate_limit(token, amount)?;
+
+        bridge_pool_instance
+            .used_hashes_dict
+            .set(message_hash, true);
+
+        Ok(signers)
+    }
+
+    // function to pay out an authorized withdrawal, or queue it when time locked
+    fn complete_withdrawal(
+        &mut self,
+        actor: Address,
+        token: ContractPackageHash,
+        amount: U256,
+        receiver: String,
+        message_hash: String,
+        signers: Vec<String>,
+    ) -> Result<(), Error> {
+        let bridge_pool_instance = BridgePool::instance();
+
+        // large withdrawals are only paid out by claim_withdrawal once their time lock has passed
+        if let Some(release_time) = bridge_pool_instance.get_release_time(token, amount) {
+            bridge_pool_instance.queue_withdrawal(
+                &message_hash,
+                PendingWithdrawal {
+                    token,
+                    recipient: actor,
+                    amount,
+                    release_time,
+                    relayer_fee: None,
+                },
+            );
+            self.emit(BridgePoolEvent::WithdrawalQueued {
+                message_hash,
+                signers,
+                recipient: actor,
+                token,
+                amount,
+                release_time,
+            });
+            return Ok(());
+        }
 
-        let dict = match actor {
-            Address::Account(_) => &bridge_pool_instance.account_hash_liquidities_dict,
-            Address::ContractPackage(_) => &bridge_pool_instance.hash_addr_liquidities_dict,
-            Address::ContractHash(_) => return Err(Error::UnexpectedContractHash),
-        };
-
-        let client = actor.as_account_hash().unwrap().to_string();
-
-        let clients_dict_address = dict
-            .get::<String>(token.to_formatted_string().as_str())
-            .ok_or(Error::ClientDoesNotHaveAnyKindOfLiquidity)?;
-        let clients_dict = Dict::instance(clients_dict_address.as_str());
-        let client_amount = clients_dict
-            .get::<U256>(client.as_str())
-            .ok_or(Error::ClientDoesNotHaveSpecificKindOfLiquidity)?;
-        let new_amount = client_amount
-            .checked_sub(amount)
-            .ok_or(Error::CheckedSubFail)?;
-        clients_dict.set(client.as_str(), new_amount);
+        bridge_pool_instance.pay_withdrawal(token, actor, amount)?;
 
         self.emit(BridgePoolEvent::TransferBySignature {
-            signer,
+            signers,
             receiver,
             token,
             amount,
@@ -303,6 +742,550 @@ pub trait BridgePoolContract<Storage: ContractStorage>: ContractContext<Storage>
         let res = bridge_pool_instance.check_signer(signer)?;
         Ok(res)
     }
+
+    // outer function to set the number of signers required for withdraw_signed
+    fn set_threshold(&mut self, threshold: u32) -> Result<(), Error> {
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.set_threshold(threshold)
+    }
+
+    // outer function to set the swap fee of a token
+    fn set_fee(&mut self, token_address: String, fee_bps: u32) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.set_fee(token, fee_bps)
+    }
+
+    // outer function to set the swap fee of a token for one target network
+    fn set_target_network_fee(
+        &mut self,
+        token_address: String,
+        target_network: U256,
+        fee_bps: u32,
+    ) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.set_target_network_fee(token, target_network, fee_bps)
+    }
+
+    // outer function to set the address collected fees are paid to
+    fn set_fee_recipient(&mut self, fee_recipient: Address) -> Result<(), Error> {
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.set_fee_recipient(fee_recipient);
+        self.emit(BridgePoolEvent::FeeRecipientSet { fee_recipient });
+        Ok(())
+    }
+
+    // outer function to pay the collected fees of a token to the fee recipient
+    fn withdraw_fees(&mut self, token_address: String) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        let (recipient, amount) = bridge_pool_instance.withdraw_fees(token)?;
+        self.emit(BridgePoolEvent::FeesWithdrawn {
+            token,
+            recipient,
+            amount,
+        });
+        Ok(())
+    }
+
+    // outer function to limit the amount of a token withdrawn per rolling window
+    fn set_rate_limit(
+        &mut self,
+        token_address: String,
+        limit: U256,
+        window: u64,
+    ) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.set_rate_limit(token, limit, window)
+    }
+
+    // outer function to lift the withdrawal rate limit of a token
+    fn remove_rate_limit(&mut self, token_address: String) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.remove_rate_limit(token)
+    }
+
+    // outer function to time lock withdrawals of a token above `threshold` for `delay` ms
+    fn set_withdrawal_delay(
+        &mut self,
+        token_address: String,
+        threshold: U256,
+        delay: u64,
+    ) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.set_withdrawal_delay(token, threshold, delay)
+    }
+
+    // outer function to claim a pending withdrawal once its time lock has passed, relayed
+    // withdrawals can be claimed by any account, which is paid their relayer fee
+    fn claim_withdrawal(&mut self, message_hash: String) -> Result<(), Error> {
+        let actor = detail::get_immediate_caller_address()
+            .unwrap_or_revert_with(Error::ImmediateCallerFail);
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.check_not_paused(ENTRY_POINT_WITHDRAW_SIGNED)?;
+
+        let pending_withdrawal = bridge_pool_instance.get_pending_withdrawal(&message_hash)?;
+        if pending_withdrawal.relayer_fee.is_none() && pending_withdrawal.recipient != actor {
+            return Err(Error::WrongCaller);
+        }
+        if u64::from(runtime::get_blocktime()) < pending_withdrawal.release_time {
+            return Err(Error::WithdrawalStillLocked);
+        }
+        bridge_pool_instance.remove_pending_withdrawal(&message_hash);
+
+        match pending_withdrawal.relayer_fee {
+            Some(relayer_fee) => bridge_pool_instance.pay_relayed_withdrawal(
+                pending_withdrawal.token,
+                pending_withdrawal.recipient,
+                actor,
+                pending_withdrawal.amount,
+                relayer_fee,
+            )?,
+            None => bridge_pool_instance.pay_withdrawal(
+                pending_withdrawal.token,
+                pending_withdrawal.recipient,
+                pending_withdrawal.amount,
+            )?,
+        }
+
+        let relayer_fee = pending_withdrawal.relayer_fee.unwrap_or_default();
+        self.emit(BridgePoolEvent::WithdrawalClaimed {
+            message_hash: message_hash.clone(),
+            recipient: pending_withdrawal.recipient,
+            token: pending_withdrawal.token,
+            amount: pending_withdrawal.amount - relayer_fee,
+        });
+        if !relayer_fee.is_zero() {
+            self.emit(BridgePoolEvent::RelayerFeePaid {
+                message_hash,
+                relayer: actor,
+                token: pending_withdrawal.token,
+                amount: relayer_fee,
+            });
+        }
+        Ok(())
+    }
+
+    // outer function for the recipient to give up a pending withdrawal
+    fn cancel_withdrawal(&mut self, message_hash: String) -> Result<(), Error> {
+        let actor = detail::get_immediate_caller_address()
+            .unwrap_or_revert_with(Error::ImmediateCallerFail);
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.check_not_paused(ENTRY_POINT_WITHDRAW_SIGNED)?;
+        let pending_withdrawal = bridge_pool_instance.get_pending_withdrawal(&message_hash)?;
+        if pending_withdrawal.recipient != actor {
+            return Err(Error::WrongCaller);
+        }
+        bridge_pool_instance.remove_pending_withdrawal(&message_hash);
+        bridge_pool_instance
+            .refund_rate_limit(pending_withdrawal.token, pending_withdrawal.amount)?;
+
+        self.emit(BridgePoolEvent::WithdrawalCancelled {
+            message_hash,
+            recipient: pending_withdrawal.recipient,
+            token: pending_withdrawal.token,
+            amount: pending_withdrawal.amount,
+        });
+        Ok(())
+    }
+
+    // outer function for admins to veto a pending withdrawal
+    fn veto_withdrawal(&mut self, message_hash: String) -> Result<(), Error> {
+        let bridge_pool_instance = BridgePool::instance();
+        let pending_withdrawal = bridge_pool_instance.get_pending_withdrawal(&message_hash)?;
+        bridge_pool_instance.remove_pending_withdrawal(&message_hash);
+        bridge_pool_instance
+            .refund_rate_limit(pending_withdrawal.token, pending_withdrawal.amount)?;
+
+        self.emit(BridgePoolEvent::WithdrawalVetoed {
+            message_hash,
+            recipient: pending_withdrawal.recipient,
+            token: pending_withdrawal.token,
+            amount: pending_withdrawal.amount,
+        });
+        Ok(())
+    }
+
+    // outer function to pause an operation
+    fn pause(&mut self, operation: String) -> Result<(), Error> {
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.pause(&operation)?;
+        self.emit(BridgePoolEvent::BridgePaused { operation });
+        Ok(())
+    }
+
+    // outer function to unpause an operation
+    fn unpause(&mut self, operation: String) -> Result<(), Error> {
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.unpause(&operation)?;
+        self.emit(BridgePoolEvent::BridgeUnpaused { operation });
+        Ok(())
+    }
+
+    // outer function to disable a token
+    fn disable_token(&mut self, token_address: String) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.disable_token(token)?;
+        self.emit(BridgePoolEvent::TokenDisabled { token });
+        Ok(())
+    }
+
+    // outer function to enable a token
+    fn enable_token(&mut self, token_address: String) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.enable_token(token)?;
+        self.emit(BridgePoolEvent::TokenEnabled { token });
+        Ok(())
+    }
+
+    // outer function to get the status of a token
+    fn get_token_status(&mut self, token_address: String) -> Result<String, Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.get_token_status(token)
+    }
+
+    // outer function to get the target token allowed for a target network
+    fn get_allowed_target(
+        &mut self,
+        token_address: String,
+        target_network: U256,
+    ) -> Result<String, Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.get_allowed_target(token, target_network)
+    }
+
+    // outer function to set the swap limits of a target network
+    fn set_swap_limits(
+        &mut self,
+        token_address: String,
+        target_network: U256,
+        min_amount: U256,
+        max_amount: U256,
+    ) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.set_swap_limits(token, target_network, min_amount, max_amount)
+    }
+
+    // outer function to get the swap limits of a target network
+    fn get_swap_limits(
+        &mut self,
+        token_address: String,
+        target_network: U256,
+    ) -> Result<(U256, U256), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.get_swap_limits(token, target_network)
+    }
+
+    // outer function to register the LP token of a token
+    fn set_lp_token(
+        &mut self,
+        token_address: String,
+        lp_token_address: String,
+    ) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+        let lp_token = ContractPackageHash::from_formatted_str(lp_token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.set_lp_token(token, lp_token)
+    }
+
+    // outer function to get the LP token of a token
+    fn get_lp_token(&mut self, token_address: String) -> Result<String, Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance
+            .get_lp_token(token)
+            .map(|lp_token| lp_token.to_formatted_string())
+            .ok_or(Error::NoLpToken)
+    }
+
+    // outer function to set the share of the swap fees of a token paid to liquidity providers
+    fn set_reward_share(
+        &mut self,
+        token_address: String,
+        reward_share_bps: u32,
+    ) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.set_reward_share(token, reward_share_bps)
+    }
+
+    // outer function to pay out the rewards earned by the caller as a liquidity provider
+    fn claim_rewards(&mut self, token_address: String) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let provider = detail::get_immediate_caller_address()
+            .unwrap_or_revert_with(Error::ImmediateCallerFail);
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.check_not_paused(ENTRY_POINT_CLAIM_REWARDS)?;
+
+        let amount = bridge_pool_instance.claim_rewards(token, provider)?;
+        self.emit(BridgePoolEvent::RewardsClaimed {
+            token,
+            provider,
+            amount,
+        });
+        Ok(())
+    }
+
+    // outer function called by an LP token on every transfer to move the reward shares along
+    fn transfer_reward_shares(
+        &mut self,
+        owner: Address,
+        recipient: Address,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let lp_token = detail::get_immediate_caller_address()
+            .unwrap_or_revert_with(Error::ImmediateCallerFail)
+            .as_contract_package_hash()
+            .copied()
+            .ok_or(Error::NotLpToken)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.transfer_reward_shares(lp_token, owner, recipient, amount)
+    }
+
+    // outer function to get the rewards the caller can claim as a liquidity provider
+    fn get_pending_rewards(&mut self, token_address: String) -> Result<U256, Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let provider = detail::get_immediate_caller_address()
+            .unwrap_or_revert_with(Error::ImmediateCallerFail);
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.get_pending_rewards(token, provider)
+    }
+
+    // outer function to get the liquidity held by the pool for a token
+    fn get_total_liquidity(&mut self, token_address: String) -> Result<U256, Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        Ok(bridge_pool_instance.get_total_liquidity(token))
+    }
+
+    // outer function to get the balance of a token held by the pool
+    fn get_pool_balance(&mut self, token_address: String) -> Result<U256, Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        Ok(bridge_pool_instance.get_pool_balance(token))
+    }
+
+    // outer function to select the interface a token is called through
+    fn set_token_standard(
+        &mut self,
+        token_address: String,
+        token_standard: String,
+    ) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+        let token_standard = TokenStandard::from_name(&token_standard)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.set_token_standard(token, token_standard)
+    }
+
+    // outer function to get the interface a token is called through
+    fn get_token_standard(&mut self, token_address: String) -> Result<String, Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.get_token_name(token)?;
+        Ok(bridge_pool_instance
+            .get_token_standard(token)
+            .name()
+            .to_string())
+    }
+
+    // outer function to credit a token by the change of the pool balance
+    fn set_balance_delta(
+        &mut self,
+        token_address: String,
+        balance_delta: bool,
+    ) -> Result<(), Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.set_balance_delta(token, balance_delta)
+    }
+
+    // outer function to get whether a token is credited by the change of the pool balance
+    fn get_balance_delta(&mut self, token_address: String) -> Result<bool, Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.get_token_name(token)?;
+        Ok(bridge_pool_instance.is_balance_delta(token))
+    }
+
+    // outer function to get the name a token was registered with
+    fn get_token_name(&mut self, token_address: String) -> Result<String, Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.get_token_name(token)
+    }
+
+    // outer function to list the target networks allowed for a token
+    fn list_target_networks(&mut self, token_address: String) -> Result<Vec<U256>, Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.list_target_networks(token)
+    }
+
+    // outer function to create the storage missing from an installed pool and move its entries
+    // to typed keys, called once by the installer when it adds a new version of the pool
+    fn upgrade(
+        &mut self,
+        chain_name: String,
+        token_addresses: Vec<String>,
+        target_networks: Vec<U256>,
+        providers: Vec<Address>,
+        signers: Vec<String>,
+    ) -> Result<(), Error> {
+        BridgePool::init(chain_name);
+        self.migrate_storage(token_addresses, target_networks, providers, signers)
+    }
+
+    // outer function to move the pool storage written under string keys to typed keys
+    fn migrate_storage(
+        &mut self,
+        token_addresses: Vec<String>,
+        target_networks: Vec<U256>,
+        providers: Vec<Address>,
+        signers: Vec<String>,
+    ) -> Result<(), Error> {
+        let tokens = token_addresses
+            .iter()
+            .map(|token_address| {
+                ContractPackageHash::from_formatted_str(token_address.as_str())
+                    .map_err(|_| Error::NotContractPackageHash)
+            })
+            .collect::<Result<Vec<ContractPackageHash>, Error>>()?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.migrate_storage(tokens, target_networks, providers, signers)
+    }
+
+    // outer function to list the registered signers
+    fn list_signers(&mut self, offset: u32, limit: u32) -> Result<Vec<String>, Error> {
+        let bridge_pool_instance = BridgePool::instance();
+        Ok(bridge_pool_instance.list_signers(offset, limit))
+    }
+
+    // outer function to list the tokens registered with allow_target
+    fn list_tokens(&mut self, offset: u32, limit: u32) -> Result<Vec<String>, Error> {
+        let bridge_pool_instance = BridgePool::instance();
+        Ok(bridge_pool_instance
+            .list_tokens(offset, limit)
+            .iter()
+            .map(|token| token.to_formatted_string())
+            .collect())
+    }
+
+    // outer function to list the providers holding liquidity of a token
+    fn list_liquidity_providers(
+        &mut self,
+        token_address: String,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Address>, Error> {
+        let token = ContractPackageHash::from_formatted_str(token_address.as_str())
+            .map_err(|_| Error::NotContractPackageHash)?;
+
+        let bridge_pool_instance = BridgePool::instance();
+        bridge_pool_instance.list_liquidity_providers(token, offset, limit)
+    }
+}
+
+// function to recover the address of the signer of a message hash
+fn recover_signer(message_hash: &[u8], signature: &str) -> Result<String, Error> {
+    let signature = hex::decode(signature).map_err(|_| Error::SignatureHexFail)?;
+
+    let sig = if signature.len() == 65 {
+        RecoverableSignature::from_bytes(&signature[..])
+            .map_err(|_| Error::RecoverableSignatureTryFromFail)?
+    } else {
+        NonRecoverableSignature::from_bytes(&signature[..])
+            .map_err(|_| Error::NonRecoverableSignatureTryFromFail)?
+    };
+
+    let s = Secp256k1::new();
+    let msg = Message::from_slice(message_hash).map_err(|_| Error::EcdsaPublicKeyRecoveryFail)?;
+    let mut sig_compact: Vec<u8> = sig.r().to_bytes().to_vec();
+    sig_compact.extend(&sig.s().to_bytes().to_vec());
+    let id_u8: u8 = From::from(sig.recovery_id());
+    let sig_v = secp256k1::ecdsa::RecoveryId::from_i32(id_u8 as i32)
+        .map_err(|_| Error::EcdsaPublicKeyRecoveryFail)?;
+    let rec_sig = secp256k1::ecdsa::RecoverableSignature::from_compact(&sig_compact, sig_v)
+        .map_err(|_| Error::EcdsaPublicKeyRecoveryFail)?;
+    let pub_key = s
+        .recover_ecdsa(&msg, &rec_sig)
+        .map_err(|_| Error::EcdsaPublicKeyRecoveryFail)?;
+    let public_key = Vec::from(&keccak256_hash(&pub_key.serialize_uncompressed()[1..])[12..]);
+
+    Ok(hex::encode(public_key))
+}
+
+fn decode_salt(salt: String) -> Result<[u8; 32], Error> {
+    hex::decode(salt)
+        .map_err(|_| Error::SaltHexFail)?
+        .try_into()
+        .map_err(|_| Error::SaltWrongSize)
 }
 
 fn is_lowercase(s: &str) -> bool {
@@ -1,3 +1,5 @@
+use casper_types::{ContractPackageHash, U256};
+
 // General constants
 pub const CONTRACT_VERSION_KEY: &str = "version";
 pub const CONTRACT_KEY: &str = "bridge_pool";
@@ -6,6 +8,10 @@ pub const BRIDGE_POOL_CONTRACT_HASH: &str = "bridge_pool_contract_hash";
 pub const BRIDGE_POOL_PACKAGE_NAME: &str = "bridge_pool_package_name";
 pub const BRIDGE_POOL_ACCESS_UREF: &str = "bridge_pool_access_uref";
 pub const CONTRACT_PACKAGE_HASH: &str = "contract_package_hash";
+pub const WITHDRAW_DOMAIN_VERSION: &str = "1";
+// Result of a withdrawal of withdraw_signed_batch that was paid out or queued, failed ones
+// report their error code
+pub const WITHDRAWAL_SUCCEEDED: u16 = 0;
 
 // Group constants
 pub const CONSTRUCTOR_GROUP: &str = "constructor_group";
@@ -14,19 +20,81 @@ pub const ADMIN_ACCESS_UREF: &str = "admin_access_uref";
 
 // Bridge pool entry point constants
 pub const ENTRY_POINT_GET_LIQUIDITY: &str = "get_liquidity";
+pub const ENTRY_POINT_GET_LIQUIDITY_OF: &str = "get_liquidity_of";
 pub const ENTRY_POINT_ADD_LIQUIDITY: &str = "add_liquidity";
+pub const ENTRY_POINT_ADD_LIQUIDITY_NATIVE: &str = "add_liquidity_native";
+pub const ENTRY_POINT_ADD_LIQUIDITY_WITH_PERMIT: &str = "add_liquidity_with_permit";
 pub const ENTRY_POINT_REMOVE_LIQUIDITY: &str = "remove_liquidity";
 pub const ENTRY_POINT_SWAP: &str = "swap";
+pub const ENTRY_POINT_SWAP_NATIVE: &str = "swap_native";
+pub const ENTRY_POINT_SWAP_WITH_PERMIT: &str = "swap_with_permit";
 pub const ENTRY_POINT_ALLOW_TARGET: &str = "allow_target";
 pub const ENTRY_POINT_WITHDRAW_SIGNED: &str = "withdraw_signed";
+pub const ENTRY_POINT_WITHDRAW_SIGNED_NATIVE: &str = "withdraw_signed_native";
+pub const ENTRY_POINT_WITHDRAW_SIGNED_BATCH: &str = "withdraw_signed_batch";
+pub const ENTRY_POINT_WITHDRAW_SIGNED_RELAYED: &str = "withdraw_signed_relayed";
 pub const ENTRY_POINT_ADD_SIGNER: &str = "add_signer";
 pub const ENTRY_POINT_REMOVE_SIGNER: &str = "remove_signer";
 pub const ENTRY_POINT_CONSTRUCTOR: &str = "constructor";
 pub const ENTRY_POINT_CHECK_SIGNER: &str = "check_signer";
+pub const ENTRY_POINT_SET_THRESHOLD: &str = "set_threshold";
+pub const ENTRY_POINT_PAUSE: &str = "pause";
+pub const ENTRY_POINT_UNPAUSE: &str = "unpause";
+pub const ENTRY_POINT_DISABLE_TOKEN: &str = "disable_token";
+pub const ENTRY_POINT_ENABLE_TOKEN: &str = "enable_token";
+pub const ENTRY_POINT_GET_TOKEN_STATUS: &str = "get_token_status";
+pub const ENTRY_POINT_DISALLOW_TARGET: &str = "disallow_target";
+pub const ENTRY_POINT_UPDATE_TARGET: &str = "update_target";
+pub const ENTRY_POINT_GET_ALLOWED_TARGET: &str = "get_allowed_target";
+pub const ENTRY_POINT_GET_TOKEN_NAME: &str = "get_token_name";
+pub const ENTRY_POINT_LIST_TARGET_NETWORKS: &str = "list_target_networks";
+pub const ENTRY_POINT_SET_FEE: &str = "set_fee";
+pub const ENTRY_POINT_SET_TARGET_NETWORK_FEE: &str = "set_target_network_fee";
+pub const ENTRY_POINT_SET_FEE_RECIPIENT: &str = "set_fee_recipient";
+pub const ENTRY_POINT_WITHDRAW_FEES: &str = "withdraw_fees";
+pub const ENTRY_POINT_SET_SWAP_LIMITS: &str = "set_swap_limits";
+pub const ENTRY_POINT_GET_SWAP_LIMITS: &str = "get_swap_limits";
+pub const ENTRY_POINT_SET_RATE_LIMIT: &str = "set_rate_limit";
+pub const ENTRY_POINT_REMOVE_RATE_LIMIT: &str = "remove_rate_limit";
+pub const ENTRY_POINT_SET_WITHDRAWAL_DELAY: &str = "set_withdrawal_delay";
+pub const ENTRY_POINT_CLAIM_WITHDRAWAL: &str = "claim_withdrawal";
+pub const ENTRY_POINT_CANCEL_WITHDRAWAL: &str = "cancel_withdrawal";
+pub const ENTRY_POINT_VETO_WITHDRAWAL: &str = "veto_withdrawal";
+pub const ENTRY_POINT_SET_LP_TOKEN: &str = "set_lp_token";
+pub const ENTRY_POINT_GET_LP_TOKEN: &str = "get_lp_token";
+pub const ENTRY_POINT_SET_REWARD_SHARE: &str = "set_reward_share";
+pub const ENTRY_POINT_CLAIM_REWARDS: &str = "claim_rewards";
+pub const ENTRY_POINT_GET_PENDING_REWARDS: &str = "get_pending_rewards";
+pub const ENTRY_POINT_TRANSFER_REWARD_SHARES: &str = "transfer_reward_shares";
+pub const ENTRY_POINT_GET_TOTAL_LIQUIDITY: &str = "get_total_liquidity";
+pub const ENTRY_POINT_GET_POOL_BALANCE: &str = "get_pool_balance";
+pub const ENTRY_POINT_SET_TOKEN_STANDARD: &str = "set_token_standard";
+pub const ENTRY_POINT_GET_TOKEN_STANDARD: &str = "get_token_standard";
+pub const ENTRY_POINT_SET_BALANCE_DELTA: &str = "set_balance_delta";
+pub const ENTRY_POINT_GET_BALANCE_DELTA: &str = "get_balance_delta";
+pub const ENTRY_POINT_MIGRATE_STORAGE: &str = "migrate_storage";
+pub const ENTRY_POINT_UPGRADE: &str = "upgrade";
+pub const ENTRY_POINT_LIST_SIGNERS: &str = "list_signers";
+pub const ENTRY_POINT_LIST_TOKENS: &str = "list_tokens";
+pub const ENTRY_POINT_LIST_LIQUIDITY_PROVIDERS: &str = "list_liquidity_providers";
+
+// Operations that can be paused, named after the entry point they guard
+pub const PAUSABLE_OPERATIONS: [&str; 5] = [
+    ENTRY_POINT_ADD_LIQUIDITY,
+    ENTRY_POINT_REMOVE_LIQUIDITY,
+    ENTRY_POINT_SWAP,
+    ENTRY_POINT_WITHDRAW_SIGNED,
+    ENTRY_POINT_CLAIM_REWARDS,
+];
 
 // ERC20 entry point constants
 pub const ERC20_ENTRY_POINT_TRANSFER: &str = "transfer";
 pub const ERC20_ENTRY_POINT_TRANSFER_FROM: &str = "transfer_from";
+pub const ERC20_ENTRY_POINT_BALANCE_OF: &str = "balance_of";
+pub const ERC20_ENTRY_POINT_ALLOWANCE: &str = "allowance";
+pub const ERC20_ENTRY_POINT_MINT: &str = "mint";
+pub const ERC20_ENTRY_POINT_BURN: &str = "burn";
+pub const ERC20_ENTRY_POINT_PERMIT: &str = "permit";
 
 // Agruments constants
 pub const AMOUNT: &str = "amount";
@@ -43,18 +111,101 @@ pub const RECIPIENT: &str = "recipient";
 pub const OWNER: &str = "owner";
 pub const SALT: &str = "salt";
 pub const SIGNATURE: &str = "signature";
+pub const SIGNATURES: &str = "signatures";
+pub const SIGNERS: &str = "signers";
+pub const THRESHOLD: &str = "threshold";
 pub const CHAIN_ID: &str = "chain_id";
+pub const EXPIRY: &str = "expiry";
+pub const CHAIN_NAME: &str = "chain_name";
+pub const OPERATION: &str = "operation";
 pub const TOKEN_RECIPIENT: &str = "token_recipient";
 pub const CALLER: &str = "caller";
 pub const ACTOR: &str = "actor";
+pub const FEE: &str = "fee";
+pub const FEE_BPS: &str = "fee_bps";
+pub const MIN_AMOUNT: &str = "min_amount";
+pub const MAX_AMOUNT: &str = "max_amount";
+pub const LIMIT: &str = "limit";
+pub const WINDOW: &str = "window";
+pub const DELAY: &str = "delay";
+pub const MESSAGE_HASH: &str = "message_hash";
+pub const RELEASE_TIME: &str = "release_time";
+pub const ADDRESS: &str = "address";
+pub const LP_TOKEN_ADDRESS: &str = "lp_token_address";
+pub const PURSE: &str = "purse";
+pub const REWARD_SHARE_BPS: &str = "reward_share_bps";
+pub const PROVIDER: &str = "provider";
+pub const TOKEN_STANDARD: &str = "token_standard";
+pub const BALANCE_DELTA: &str = "balance_delta";
+pub const WITHDRAWALS: &str = "withdrawals";
+pub const REVERT_ON_FAILURE: &str = "revert_on_failure";
+pub const RELAYER_FEE: &str = "relayer_fee";
+pub const RELAYER: &str = "relayer";
+pub const SPENDER: &str = "spender";
+pub const DEADLINE: &str = "deadline";
+pub const TOKEN_ADDRESSES: &str = "token_addresses";
+pub const PROVIDERS: &str = "providers";
+pub const TARGET_NETWORKS: &str = "target_networks";
+pub const OFFSET: &str = "offset";
 
 // Dictionary name constants
 pub const ACCOUNT_HASH_LIQUIDITIES_DICT: &str = "account_hash_liquidities_dict";
 pub const HASH_ADDR_LIQUIDITIES_DICT: &str = "hash_addr_liquidities_dict";
 pub const ALLOWED_TARGETS_DICT: &str = "allowed_targets_dict";
+pub const SWAP_LIMITS_DICT: &str = "swap_limits_dict";
 pub const USED_HASHES_DICT: &str = "used_hashes_dict";
 pub const SIGNERS_DICT: &str = "signers_dict";
 pub const TOKEN_CONTRACT_PACKAGE_HASH_DICT_NAME: &str = "token_contract_package_hash_dict_name";
+pub const PAUSED_OPERATIONS_DICT: &str = "paused_operations_dict";
+pub const DISABLED_TOKENS_DICT: &str = "disabled_tokens_dict";
+pub const TARGET_NETWORKS_DICT: &str = "target_networks_dict";
+pub const TARGET_NETWORK_COUNTS_DICT: &str = "target_network_counts_dict";
+pub const TARGET_NETWORK_INDEXES_DICT: &str = "target_network_indexes_dict";
+pub const FEES_DICT: &str = "fees_dict";
+pub const TARGET_NETWORK_FEES_DICT: &str = "target_network_fees_dict";
+pub const COLLECTED_FEES_DICT: &str = "collected_fees_dict";
+pub const RATE_LIMITS_DICT: &str = "rate_limits_dict";
+pub const RATE_LIMIT_CAPACITIES_DICT: &str = "rate_limit_capacities_dict";
+pub const WITHDRAWAL_DELAYS_DICT: &str = "withdrawal_delays_dict";
+pub const PENDING_WITHDRAWALS_DICT: &str = "pending_withdrawals_dict";
+pub const LP_TOKENS_DICT: &str = "lp_tokens_dict";
+pub const LP_TOKEN_UNDERLYINGS_DICT: &str = "lp_token_underlyings_dict";
+pub const REWARDS_DICT: &str = "rewards_dict";
+pub const PROVIDER_REWARDS_DICT: &str = "provider_rewards_dict";
+pub const REWARD_SHARES_DICT: &str = "reward_shares_dict";
+pub const TOTAL_LIQUIDITY_DICT: &str = "total_liquidity_dict";
+pub const TOKEN_STANDARDS_DICT: &str = "token_standards_dict";
+pub const BALANCE_DELTA_TOKENS_DICT: &str = "balance_delta_tokens_dict";
+pub const LIQUIDITY_PROVIDERS_DICT: &str = "liquidity_providers_dict";
+
+// Named key constants
+pub const SIGNERS_THRESHOLD: &str = "signers_threshold";
+pub const CASPER_CHAIN_NAME: &str = "casper_chain_name";
+pub const FEE_RECIPIENT: &str = "fee_recipient";
+pub const POOL_PURSE: &str = "pool_purse";
+
+// Suffixes telling apart the min and max swap limits of a target network in SWAP_LIMITS_DICT
+pub const SWAP_MIN_AMOUNT_SUFFIX: &str = "_min_amount";
+pub const SWAP_MAX_AMOUNT_SUFFIX: &str = "_max_amount";
+
+// Fee constants, fees are expressed in basis points
+pub const FEE_DENOMINATOR: u32 = 10_000;
+// a swap fee can take at most 10% of the swapped amount
+pub const MAX_FEE_BPS: u32 = 1_000;
+
+// Scale of the accumulated reward per share of liquidity providers
+pub const REWARD_PRECISION: U256 = U256([1_000_000_000_000_000_000, 0, 0, 0]);
+
+// Native CSPR is bridged as the token with the zero contract package hash, held in the pool purse
+pub const NATIVE_TOKEN: ContractPackageHash = ContractPackageHash::new([0u8; 32]);
+
+// Token status constants
+pub const TOKEN_STATUS_ACTIVE: &str = "active";
+pub const TOKEN_STATUS_DISABLED: &str = "disabled";
+
+// Token standard constants, the interface used to call a token
+pub const TOKEN_STANDARD_ERC20: &str = "erc20";
+pub const TOKEN_STANDARD_CEP18: &str = "cep18";
 
 // Event constants
 pub const EVENT_TYPE: &str = "event_type";
@@ -62,3 +213,17 @@ pub const EVENT_BRIDGE_LIQUIDITY_ADDED: &str = "bridge_liquidity_added";
 pub const EVENT_BRIDGE_LIQUIDITY_REMOVED: &str = "bridge_liquidity_removed";
 pub const EVENT_BRIDGE_SWAP: &str = "bridge_swap";
 pub const EVENT_BRIDGE_TRANSFER_BY_SIGNATURE: &str = "bridge_transfer_by_signature";
+pub const EVENT_BRIDGE_PAUSED: &str = "bridge_paused";
+pub const EVENT_BRIDGE_UNPAUSED: &str = "bridge_unpaused";
+pub const EVENT_BRIDGE_TOKEN_DISABLED: &str = "bridge_token_disabled";
+pub const EVENT_BRIDGE_TOKEN_ENABLED: &str = "bridge_token_enabled";
+pub const EVENT_BRIDGE_TARGET_DISALLOWED: &str = "bridge_target_disallowed";
+pub const EVENT_BRIDGE_TARGET_UPDATED: &str = "bridge_target_updated";
+pub const EVENT_BRIDGE_FEES_WITHDRAWN: &str = "bridge_fees_withdrawn";
+pub const EVENT_BRIDGE_WITHDRAWAL_QUEUED: &str = "bridge_withdrawal_queued";
+pub const EVENT_BRIDGE_WITHDRAWAL_CLAIMED: &str = "bridge_withdrawal_claimed";
+pub const EVENT_BRIDGE_WITHDRAWAL_CANCELLED: &str = "bridge_withdrawal_cancelled";
+pub const EVENT_BRIDGE_WITHDRAWAL_VETOED: &str = "bridge_withdrawal_vetoed";
+pub const EVENT_BRIDGE_REWARDS_CLAIMED: &str = "bridge_rewards_claimed";
+pub const EVENT_BRIDGE_RELAYER_FEE_PAID: &str = "bridge_relayer_fee_paid";
+pub const EVENT_BRIDGE_FEE_RECIPIENT_SET: &str = "bridge_fee_recipient_set";
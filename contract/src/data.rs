@@ -1,14 +1,33 @@
 use crate::alloc::borrow::ToOwned;
 use crate::consts::{
-    ACCOUNT_HASH_LIQUIDITIES_DICT, ACTOR, ALLOWED_TARGETS_DICT, BRIDGE_POOL_CONTRACT_PACKAGE_HASH,
-    CONTRACT_PACKAGE_HASH, ERC20_ENTRY_POINT_TRANSFER, ERC20_ENTRY_POINT_TRANSFER_FROM,
-    EVENT_BRIDGE_LIQUIDITY_ADDED, EVENT_BRIDGE_LIQUIDITY_REMOVED, EVENT_BRIDGE_SWAP,
-    EVENT_BRIDGE_TRANSFER_BY_SIGNATURE, EVENT_TYPE, HASH_ADDR_LIQUIDITIES_DICT, OWNER, RECEIVER,
-    RECIPIENT, SIGNER, SIGNERS_DICT, TARGET_ADDRESS, TARGET_NETWORK, TOKEN,
-    TOKEN_CONTRACT_PACKAGE_HASH_DICT_NAME, USED_HASHES_DICT,
+    ACCOUNT_HASH_LIQUIDITIES_DICT, ACTOR, ADDRESS, ALLOWED_TARGETS_DICT, BALANCE_DELTA_TOKENS_DICT,
+    BRIDGE_POOL_CONTRACT_PACKAGE_HASH, CASPER_CHAIN_NAME, COLLECTED_FEES_DICT,
+    CONTRACT_PACKAGE_HASH, DEADLINE, DISABLED_TOKENS_DICT, ERC20_ENTRY_POINT_ALLOWANCE,
+    ERC20_ENTRY_POINT_BALANCE_OF, ERC20_ENTRY_POINT_BURN, ERC20_ENTRY_POINT_MINT,
+    ERC20_ENTRY_POINT_PERMIT, ERC20_ENTRY_POINT_TRANSFER, ERC20_ENTRY_POINT_TRANSFER_FROM,
+    EVENT_BRIDGE_FEES_WITHDRAWN, EVENT_BRIDGE_FEE_RECIPIENT_SET, EVENT_BRIDGE_LIQUIDITY_ADDED,
+    EVENT_BRIDGE_LIQUIDITY_REMOVED, EVENT_BRIDGE_PAUSED, EVENT_BRIDGE_RELAYER_FEE_PAID,
+    EVENT_BRIDGE_REWARDS_CLAIMED, EVENT_BRIDGE_SWAP, EVENT_BRIDGE_TARGET_DISALLOWED,
+    EVENT_BRIDGE_TARGET_UPDATED, EVENT_BRIDGE_TOKEN_DISABLED, EVENT_BRIDGE_TOKEN_ENABLED,
+    EVENT_BRIDGE_TRANSFER_BY_SIGNATURE, EVENT_BRIDGE_UNPAUSED, EVENT_BRIDGE_WITHDRAWAL_CANCELLED,
+    EVENT_BRIDGE_WITHDRAWAL_CLAIMED, EVENT_BRIDGE_WITHDRAWAL_QUEUED,
+    EVENT_BRIDGE_WITHDRAWAL_VETOED, EVENT_TYPE, FEE, FEES_DICT, FEE_DENOMINATOR, FEE_RECIPIENT,
+    HASH_ADDR_LIQUIDITIES_DICT, LIQUIDITY_PROVIDERS_DICT, LP_TOKENS_DICT,
+    LP_TOKEN_UNDERLYINGS_DICT, MAX_FEE_BPS, MESSAGE_HASH, NATIVE_TOKEN, OPERATION, OWNER,
+    PAUSABLE_OPERATIONS, PAUSED_OPERATIONS_DICT, PENDING_WITHDRAWALS_DICT, POOL_PURSE, PROVIDER,
+    PROVIDER_REWARDS_DICT, RATE_LIMITS_DICT, RATE_LIMIT_CAPACITIES_DICT, RECEIVER, RECIPIENT,
+    RELAYER, RELEASE_TIME, REWARDS_DICT, REWARD_PRECISION, REWARD_SHARES_DICT, SIGNATURE, SIGNERS,
+    SIGNERS_DICT, SIGNERS_THRESHOLD, SPENDER, SWAP_LIMITS_DICT, SWAP_MAX_AMOUNT_SUFFIX,
+    SWAP_MIN_AMOUNT_SUFFIX, TARGET_ADDRESS, TARGET_NETWORK, TARGET_NETWORKS_DICT,
+    TARGET_NETWORK_COUNTS_DICT, TARGET_NETWORK_FEES_DICT, TARGET_NETWORK_INDEXES_DICT,
+    TARGET_TOKEN, TOKEN, TOKEN_CONTRACT_PACKAGE_HASH_DICT_NAME, TOKEN_STANDARDS_DICT,
+    TOKEN_STATUS_ACTIVE, TOKEN_STATUS_DISABLED, TOTAL_LIQUIDITY_DICT, USED_HASHES_DICT,
+    WITHDRAWAL_DELAYS_DICT,
 };
 use crate::error::Error;
 use crate::event::BridgePoolEvent;
+use crate::pending_withdrawal::PendingWithdrawal;
+use crate::token_standard::{IgnoredReturnValue, TokenStandard};
 use crate::{address::Address, consts::AMOUNT};
 use alloc::{
     collections::BTreeMap,
@@ -18,50 +37,151 @@ use alloc::{
 use casper_contract::{
     contract_api::{
         runtime::{self, get_call_stack},
-        storage,
+        storage, system,
     },
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::RuntimeArgs;
-use casper_types::{runtime_args, system::CallStackElement, ContractPackageHash, URef, U256};
-use contract_utils::Dict;
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    runtime_args,
+    system::CallStackElement,
+    CLTyped, ContractPackageHash, Key, URef, U256, U512,
+};
+use contract_utils::{
+    get_key, set_key, Dict, EnumerableDict, EnumerableNestedDict, NestedDict, TypedDict,
+};
 
 pub struct BridgePool {
-    // dictionary to track client conected dictionaries
-    pub account_hash_liquidities_dict: Dict,
-    // dictionary to track external contracts' conected dictionaries
-    pub hash_addr_liquidities_dict: Dict,
-    // dictionary to track allowed targets
-    pub allowed_targets_dict: Dict,
+    // liquidity per token and account
+    pub account_hash_liquidities_dict: NestedDict<ContractPackageHash, Address, U256>,
+    // liquidity per token and external contract
+    pub hash_addr_liquidities_dict: NestedDict<ContractPackageHash, Address, U256>,
+    // target token per token and target network, with the swap limits of the target network
+    pub allowed_targets_dict: NestedDict<ContractPackageHash, U256, String>,
+    pub swap_limits_dict: TypedDict<(ContractPackageHash, U256, &'static str), U256>,
     // dictionary to track used hashes
     #[allow(unused)]
-    pub used_hashes_dict: Dict,
+    pub used_hashes_dict: TypedDict<String, bool>,
     // dictionary to track signers
-    pub signers_dict: Dict,
-    token_contract_package_hash_dict_name: Dict,
+    pub signers_dict: EnumerableDict<String, bool>,
+    token_contract_package_hash_dict_name: EnumerableDict<ContractPackageHash, String>,
+    // providers holding liquidity per token
+    pub liquidity_providers_dict: EnumerableNestedDict<ContractPackageHash, Address, bool>,
+    // dictionary to track paused operations
+    pub paused_operations_dict: TypedDict<String, bool>,
+    // dictionary to track disabled tokens
+    pub disabled_tokens_dict: TypedDict<ContractPackageHash, bool>,
+    // enumerable index of the target networks of every token
+    pub target_network_counts_dict: TypedDict<ContractPackageHash, u32>,
+    pub target_networks_dict: NestedDict<ContractPackageHash, u32, U256>,
+    pub target_network_indexes_dict: NestedDict<ContractPackageHash, U256, u32>,
+    // fee in basis points per token, or per token and target network
+    pub fees_dict: TypedDict<ContractPackageHash, u32>,
+    pub target_network_fees_dict: NestedDict<ContractPackageHash, U256, u32>,
+    pub collected_fees_dict: TypedDict<ContractPackageHash, U256>,
+    // withdrawal (limit, window) and (remaining capacity, last update) per token
+    pub rate_limits_dict: TypedDict<ContractPackageHash, (U256, u64)>,
+    pub rate_limit_capacities_dict: TypedDict<ContractPackageHash, (U256, u64)>,
+    // (threshold, delay) of time locked withdrawals per token
+    pub withdrawal_delays_dict: TypedDict<ContractPackageHash, (U256, u64)>,
+    // pending withdrawals keyed by the message hash of their authorization
+    pub pending_withdrawals_dict: TypedDict<String, PendingWithdrawal>,
+    // LP token contract package of every token, and the token of every LP token
+    pub lp_tokens_dict: TypedDict<ContractPackageHash, ContractPackageHash>,
+    pub lp_token_underlyings_dict: TypedDict<ContractPackageHash, ContractPackageHash>,
+    // (accumulated reward per share, total shares) per token, (shares, reward debt, pending
+    // rewards) per token and liquidity provider, and share of the swap fees paid to liquidity
+    // providers per token
+    pub rewards_dict: TypedDict<ContractPackageHash, (U256, U256)>,
+    pub provider_rewards_dict: NestedDict<ContractPackageHash, Address, (U256, U256, U256)>,
+    pub reward_shares_dict: TypedDict<ContractPackageHash, u32>,
+    // liquidity held by the pool per token
+    pub total_liquidity_dict: TypedDict<ContractPackageHash, U256>,
+    // interface used to call every token, the erc20 layout when not set
+    pub token_standards_dict: TypedDict<ContractPackageHash, String>,
+    // tokens credited by the change of the pool balance instead of the requested amount
+    pub balance_delta_tokens_dict: TypedDict<ContractPackageHash, bool>,
 }
 
 impl BridgePool {
     pub fn instance() -> BridgePool {
         BridgePool {
-            account_hash_liquidities_dict: Dict::instance(ACCOUNT_HASH_LIQUIDITIES_DICT),
-            hash_addr_liquidities_dict: Dict::instance(HASH_ADDR_LIQUIDITIES_DICT),
-            allowed_targets_dict: Dict::instance(ALLOWED_TARGETS_DICT),
-            used_hashes_dict: Dict::instance(USED_HASHES_DICT),
-            signers_dict: Dict::instance(SIGNERS_DICT),
-            token_contract_package_hash_dict_name: Dict::instance(
+            account_hash_liquidities_dict: NestedDict::instance(ACCOUNT_HASH_LIQUIDITIES_DICT),
+            hash_addr_liquidities_dict: NestedDict::instance(HASH_ADDR_LIQUIDITIES_DICT),
+            allowed_targets_dict: NestedDict::instance(ALLOWED_TARGETS_DICT),
+            swap_limits_dict: TypedDict::instance(SWAP_LIMITS_DICT),
+            used_hashes_dict: TypedDict::instance(USED_HASHES_DICT),
+            signers_dict: EnumerableDict::instance(SIGNERS_DICT),
+            token_contract_package_hash_dict_name: EnumerableDict::instance(
                 TOKEN_CONTRACT_PACKAGE_HASH_DICT_NAME,
             ),
+            liquidity_providers_dict: EnumerableNestedDict::instance(LIQUIDITY_PROVIDERS_DICT),
+            paused_operations_dict: TypedDict::instance(PAUSED_OPERATIONS_DICT),
+            disabled_tokens_dict: TypedDict::instance(DISABLED_TOKENS_DICT),
+            target_network_counts_dict: TypedDict::instance(TARGET_NETWORK_COUNTS_DICT),
+            target_networks_dict: NestedDict::instance(TARGET_NETWORKS_DICT),
+            target_network_indexes_dict: NestedDict::instance(TARGET_NETWORK_INDEXES_DICT),
+            fees_dict: TypedDict::instance(FEES_DICT),
+            target_network_fees_dict: NestedDict::instance(TARGET_NETWORK_FEES_DICT),
+            collected_fees_dict: TypedDict::instance(COLLECTED_FEES_DICT),
+            rate_limits_dict: TypedDict::instance(RATE_LIMITS_DICT),
+            rate_limit_capacities_dict: TypedDict::instance(RATE_LIMIT_CAPACITIES_DICT),
+            withdrawal_delays_dict: TypedDict::instance(WITHDRAWAL_DELAYS_DICT),
+            pending_withdrawals_dict: TypedDict::instance(PENDING_WITHDRAWALS_DICT),
+            lp_tokens_dict: TypedDict::instance(LP_TOKENS_DICT),
+            lp_token_underlyings_dict: TypedDict::instance(LP_TOKEN_UNDERLYINGS_DICT),
+            rewards_dict: TypedDict::instance(REWARDS_DICT),
+            provider_rewards_dict: NestedDict::instance(PROVIDER_REWARDS_DICT),
+            reward_shares_dict: TypedDict::instance(REWARD_SHARES_DICT),
+            total_liquidity_dict: TypedDict::instance(TOTAL_LIQUIDITY_DICT),
+            token_standards_dict: TypedDict::instance(TOKEN_STANDARDS_DICT),
+            balance_delta_tokens_dict: TypedDict::instance(BALANCE_DELTA_TOKENS_DICT),
         }
     }
 
-    pub fn init() {
-        Dict::init(ACCOUNT_HASH_LIQUIDITIES_DICT);
-        Dict::init(HASH_ADDR_LIQUIDITIES_DICT);
-        Dict::init(ALLOWED_TARGETS_DICT);
-        Dict::init(USED_HASHES_DICT);
-        Dict::init(SIGNERS_DICT);
-        Dict::init(TOKEN_CONTRACT_PACKAGE_HASH_DICT_NAME);
+    // creates the storage of the pool, only what is missing when upgrading an installed pool so
+    // its entries and settings are kept
+    pub fn init(chain_name: String) {
+        Dict::init_missing(ACCOUNT_HASH_LIQUIDITIES_DICT);
+        Dict::init_missing(HASH_ADDR_LIQUIDITIES_DICT);
+        Dict::init_missing(ALLOWED_TARGETS_DICT);
+        Dict::init_missing(SWAP_LIMITS_DICT);
+        Dict::init_missing(USED_HASHES_DICT);
+        EnumerableDict::<String, bool>::init_missing(SIGNERS_DICT);
+        EnumerableDict::<ContractPackageHash, String>::init_missing(
+            TOKEN_CONTRACT_PACKAGE_HASH_DICT_NAME,
+        );
+        EnumerableNestedDict::<ContractPackageHash, Address, bool>::init_missing(
+            LIQUIDITY_PROVIDERS_DICT,
+        );
+        Dict::init_missing(PAUSED_OPERATIONS_DICT);
+        Dict::init_missing(DISABLED_TOKENS_DICT);
+        Dict::init_missing(TARGET_NETWORKS_DICT);
+        Dict::init_missing(TARGET_NETWORK_COUNTS_DICT);
+        Dict::init_missing(TARGET_NETWORK_INDEXES_DICT);
+        Dict::init_missing(FEES_DICT);
+        Dict::init_missing(TARGET_NETWORK_FEES_DICT);
+        Dict::init_missing(COLLECTED_FEES_DICT);
+        Dict::init_missing(RATE_LIMITS_DICT);
+        Dict::init_missing(RATE_LIMIT_CAPACITIES_DICT);
+        Dict::init_missing(WITHDRAWAL_DELAYS_DICT);
+        Dict::init_missing(PENDING_WITHDRAWALS_DICT);
+        Dict::init_missing(LP_TOKENS_DICT);
+        Dict::init_missing(LP_TOKEN_UNDERLYINGS_DICT);
+        Dict::init_missing(REWARDS_DICT);
+        Dict::init_missing(PROVIDER_REWARDS_DICT);
+        Dict::init_missing(REWARD_SHARES_DICT);
+        Dict::init_missing(TOTAL_LIQUIDITY_DICT);
+        Dict::init_missing(TOKEN_STANDARDS_DICT);
+        Dict::init_missing(BALANCE_DELTA_TOKENS_DICT);
+        if !runtime::has_key(SIGNERS_THRESHOLD) {
+            set_key(SIGNERS_THRESHOLD, 1u32);
+        }
+        set_key(CASPER_CHAIN_NAME, chain_name);
+        if !runtime::has_key(POOL_PURSE) {
+            runtime::put_key(POOL_PURSE, system::create_purse().into());
+        }
     }
 
     // function to get liquidity already in pool by client address
@@ -70,236 +190,1083 @@ impl BridgePool {
         token_contract_hash: ContractPackageHash,
         client_address: Address,
     ) -> Result<U256, Error> {
-        let client_string: String = TryInto::try_into(client_address)?;
-        Ok(self.get_liquidity_added_by_client_generic(
-            token_contract_hash.to_string(),
-            client_string,
-            self.get_dict(client_address)?,
-        ))
+        if let Some(lp_token) = self.get_lp_token(token_contract_hash) {
+            return Ok(self.balance_of(lp_token, client_address));
+        }
+        Ok(self
+            .get_dict(client_address)?
+            .get_or_default(&token_contract_hash, &client_address))
     }
 
-    pub fn get_liquidity_added_by_client_generic(
+    // add liquidity to the pool, returns the amount credited to the client
+    pub fn add_liquidity(
         &self,
-        token_contract_hash: String,
-        client: String,
-        dict: &Dict,
-    ) -> U256 {
-        let mut res = U256::zero();
-        if let Some(token_address) = dict.get::<String>(token_contract_hash.as_str()) {
-            let clients_dict = Dict::instance(token_address.as_str());
-            if let Some(amount) = clients_dict.get(client.as_str()) {
-                res += amount;
+        bridge_pool_contract_package_hash: ContractPackageHash,
+        token_contract_package_hash: ContractPackageHash,
+        client_address: Address,
+        amount: U256,
+        purse: Option<URef>,
+    ) -> Result<U256, Error> {
+        self.check_token_enabled(token_contract_package_hash)?;
+        let amount = match purse {
+            Some(purse) => {
+                self.pay_me_from_purse(purse, amount);
+                amount
+            }
+            None if token_contract_package_hash == NATIVE_TOKEN => {
+                return Err(Error::NativeTokenNeedsPurse)
             }
+            None => self.pay_to_pool(
+                token_contract_package_hash,
+                client_address,
+                crate::address::Address::ContractPackage(bridge_pool_contract_package_hash),
+                amount,
+            )?,
+        };
+        self.add_total_liquidity(token_contract_package_hash, amount);
+
+        if let Some(lp_token) = self.get_lp_token(token_contract_package_hash) {
+            self.mint_lp(lp_token, client_address, amount);
+        } else {
+            self.get_dict(client_address)?.update_with(
+                &token_contract_package_hash,
+                &client_address,
+                |liquidity| liquidity + amount,
+            );
         }
-        res
+
+        self.update_rewards(token_contract_package_hash, client_address)?;
+        Ok(amount)
     }
 
-    // add liquidity to the pool
-    pub fn add_liquidity(
+    // remove liquidity from the pool
+    pub fn remove_liquidity(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        client_address: Address,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self.pay_from_me(token_contract_package_hash, client_address, amount);
+        self.sub_total_liquidity(token_contract_package_hash, amount)?;
+        self.deduct_liquidity(token_contract_package_hash, client_address, amount)
+    }
+
+    // burns the LP tokens of the client, or deducts the liquidity recorded for it when the token
+    // has no LP token
+    pub fn deduct_liquidity(
         &self,
-        bridge_pool_contract_package_hash: ContractPackageHash,
         token_contract_package_hash: ContractPackageHash,
         client_address: Address,
         amount: U256,
     ) -> Result<(), Error> {
-        self.pay_to(
+        if let Some(lp_token) = self.get_lp_token(token_contract_package_hash) {
+            self.burn_lp(lp_token, client_address, amount);
+        } else {
+            let dict = self.get_dict(client_address)?;
+            let liquidity = dict
+                .get(&token_contract_package_hash, &client_address)
+                .ok_or(Error::ClientDoesNotHaveAnyKindOfLiquidity)?;
+            dict.set(
+                &token_contract_package_hash,
+                &client_address,
+                liquidity.checked_sub(amount).ok_or(Error::CheckedSubFail)?,
+            );
+        }
+        self.update_rewards(token_contract_package_hash, client_address)
+    }
+
+    // function to set the share in basis points of the swap fees of a token paid to its liquidity
+    // providers, the rest is collected for the fee recipient
+    pub fn set_reward_share(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        reward_share_bps: u32,
+    ) -> Result<(), Error> {
+        if reward_share_bps > FEE_DENOMINATOR {
+            return Err(Error::RewardShareTooHigh);
+        }
+        self.get_token_name(token_contract_package_hash)?;
+        self.reward_shares_dict
+            .set(&token_contract_package_hash, reward_share_bps);
+        Ok(())
+    }
+
+    pub fn get_reward_share(&self, token_contract_package_hash: ContractPackageHash) -> u32 {
+        self.reward_shares_dict
+            .get_or_default(&token_contract_package_hash)
+    }
+
+    fn get_reward_pool(&self, token_contract_package_hash: ContractPackageHash) -> (U256, U256) {
+        self.rewards_dict
+            .get_or_default(&token_contract_package_hash)
+    }
+
+    // adds the share of a swap fee owed to the liquidity providers of a token to its accumulated
+    // reward per share, returns the amount distributed
+    fn distribute_rewards(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        fee: U256,
+    ) -> U256 {
+        let (acc_reward_per_share, total_shares) =
+            self.get_reward_pool(token_contract_package_hash);
+        if total_shares.is_zero() {
+            return U256::zero();
+        }
+        let rewards = fee * U256::from(self.get_reward_share(token_contract_package_hash))
+            / U256::from(FEE_DENOMINATOR);
+        self.rewards_dict.set(
+            &token_contract_package_hash,
+            (
+                acc_reward_per_share + rewards * REWARD_PRECISION / total_shares,
+                total_shares,
+            ),
+        );
+        rewards
+    }
+
+    // settles the rewards earned by a liquidity provider so far and tracks its current liquidity as
+    // its shares, it must be called after every change of its liquidity
+    pub fn update_rewards(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        client_address: Address,
+    ) -> Result<(), Error> {
+        let liquidity =
+            self.get_liquidity_added_by_client(token_contract_package_hash, client_address)?;
+        self.settle_rewards(token_contract_package_hash, client_address, liquidity);
+        Ok(())
+    }
+
+    // function to move the shares of `amount` LP tokens from `owner` to `recipient`, called by the
+    // LP token on every transfer so the shares of both sides keep following their LP balances
+    pub fn transfer_reward_shares(
+        &self,
+        lp_token_contract_package_hash: ContractPackageHash,
+        owner: Address,
+        recipient: Address,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let token_contract_package_hash = self
+            .lp_token_underlyings_dict
+            .get(&lp_token_contract_package_hash)
+            .ok_or(Error::NotLpToken)?;
+        let (owner_shares, _, _) = self
+            .provider_rewards_dict
+            .get_or_default(&token_contract_package_hash, &owner);
+        self.settle_rewards(
             token_contract_package_hash,
-            client_address,
-            crate::address::Address::ContractPackage(bridge_pool_contract_package_hash),
-            amount,
+            owner,
+            owner_shares
+                .checked_sub(amount)
+                .ok_or(Error::CheckedSubFail)?,
+        );
+        let (recipient_shares, _, _) = self
+            .provider_rewards_dict
+            .get_or_default(&token_contract_package_hash, &recipient);
+        self.settle_rewards(
+            token_contract_package_hash,
+            recipient,
+            recipient_shares + amount,
         );
+        Ok(())
+    }
 
-        let client_string: String = TryInto::try_into(client_address)?;
-        self.add_liquidity_generic(
-            token_contract_package_hash.to_formatted_string(),
-            client_string,
-            amount,
-            self.get_dict(client_address)?,
+    // settles the rewards earned by a liquidity provider on its former shares and replaces them
+    // with `liquidity`
+    fn settle_rewards(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        client_address: Address,
+        liquidity: U256,
+    ) {
+        self.track_liquidity_provider(token_contract_package_hash, client_address, liquidity);
+        let (acc_reward_per_share, total_shares) =
+            self.get_reward_pool(token_contract_package_hash);
+        let (shares, reward_debt, pending_rewards) = self
+            .provider_rewards_dict
+            .get_or_default(&token_contract_package_hash, &client_address);
+        let pending_rewards =
+            pending_rewards + shares * acc_reward_per_share / REWARD_PRECISION - reward_debt;
+        self.provider_rewards_dict.set(
+            &token_contract_package_hash,
+            &client_address,
+            (
+                liquidity,
+                liquidity * acc_reward_per_share / REWARD_PRECISION,
+                pending_rewards,
+            ),
+        );
+        self.rewards_dict.set(
+            &token_contract_package_hash,
+            (acc_reward_per_share, total_shares - shares + liquidity),
+        );
+    }
+
+    // providers are listed for a token as long as they hold liquidity of it
+    fn track_liquidity_provider(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        client_address: Address,
+        liquidity: U256,
+    ) {
+        if liquidity.is_zero() {
+            self.liquidity_providers_dict
+                .remove(&token_contract_package_hash, &client_address);
+        } else {
+            self.liquidity_providers_dict
+                .set(&token_contract_package_hash, &client_address, true);
+        }
+    }
+
+    // function to list at most `limit` providers holding liquidity of a token from `offset` on
+    pub fn list_liquidity_providers(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Address>, Error> {
+        self.get_token_name(token_contract_package_hash)?;
+        Ok(self
+            .liquidity_providers_dict
+            .keys(&token_contract_package_hash, offset, limit))
+    }
+
+    pub fn get_pending_rewards(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        client_address: Address,
+    ) -> Result<U256, Error> {
+        let (acc_reward_per_share, _) = self.get_reward_pool(token_contract_package_hash);
+        let (shares, reward_debt, pending_rewards) = self
+            .provider_rewards_dict
+            .get_or_default(&token_contract_package_hash, &client_address);
+        Ok(pending_rewards + shares * acc_reward_per_share / REWARD_PRECISION - reward_debt)
+    }
+
+    // function to pay out the rewards earned by a liquidity provider
+    pub fn claim_rewards(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        client_address: Address,
+    ) -> Result<U256, Error> {
+        self.update_rewards(token_contract_package_hash, client_address)?;
+        let (shares, reward_debt, pending_rewards) = self
+            .provider_rewards_dict
+            .get_or_default(&token_contract_package_hash, &client_address);
+        if pending_rewards.is_zero() {
+            return Err(Error::NoRewards);
+        }
+        self.provider_rewards_dict.set(
+            &token_contract_package_hash,
+            &client_address,
+            (shares, reward_debt, U256::zero()),
+        );
+        self.pay_from_me(token_contract_package_hash, client_address, pending_rewards);
+        Ok(pending_rewards)
+    }
+
+    // function to register the LP token minted for the liquidity of a token, it can only be set once
+    // and an LP token can only represent a single token
+    pub fn set_lp_token(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        lp_token_contract_package_hash: ContractPackageHash,
+    ) -> Result<(), Error> {
+        self.get_token_name(token_contract_package_hash)?;
+        if self.get_lp_token(token_contract_package_hash).is_some()
+            || self
+                .lp_token_underlyings_dict
+                .contains(&lp_token_contract_package_hash)
+        {
+            return Err(Error::LpTokenAlreadySet);
+        }
+        self.lp_tokens_dict
+            .set(&token_contract_package_hash, lp_token_contract_package_hash);
+        self.lp_token_underlyings_dict
+            .set(&lp_token_contract_package_hash, token_contract_package_hash);
+        Ok(())
+    }
+
+    pub fn get_lp_token(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+    ) -> Option<ContractPackageHash> {
+        self.lp_tokens_dict.get(&token_contract_package_hash)
+    }
+
+    fn mint_lp(&self, lp_token: ContractPackageHash, owner: Address, amount: U256) {
+        let args = runtime_args! {
+            OWNER => owner,
+            AMOUNT => amount
+        };
+        runtime::call_versioned_contract::<()>(lp_token, None, ERC20_ENTRY_POINT_MINT, args);
+    }
+
+    fn burn_lp(&self, lp_token: ContractPackageHash, owner: Address, amount: U256) {
+        let args = runtime_args! {
+            OWNER => owner,
+            AMOUNT => amount
+        };
+        runtime::call_versioned_contract::<()>(lp_token, None, ERC20_ENTRY_POINT_BURN, args);
+    }
+
+    fn balance_of(&self, token: ContractPackageHash, owner: Address) -> U256 {
+        let args = runtime_args! {
+            ADDRESS => owner
+        };
+        runtime::call_versioned_contract::<U256>(token, None, ERC20_ENTRY_POINT_BALANCE_OF, args)
+    }
+
+    fn allowance(&self, token: ContractPackageHash, owner: Address, spender: Address) -> U256 {
+        let args = runtime_args! {
+            OWNER => owner,
+            SPENDER => spender
+        };
+        runtime::call_versioned_contract::<U256>(token, None, ERC20_ENTRY_POINT_ALLOWANCE, args)
+    }
+
+    // function to add signer
+    pub fn add_signer(&self, signer: String) {
+        self.signers_dict.set(&signer, true)
+    }
+
+    // function to remvoe signer
+    pub fn remove_signer(&self, signer: String) {
+        self.signers_dict.remove(&signer)
+    }
+
+    // function to list at most `limit` signers from `offset` on
+    pub fn list_signers(&self, offset: u32, limit: u32) -> Vec<String> {
+        self.signers_dict.keys(offset, limit)
+    }
+
+    pub fn check_signer(&self, signer: String) -> Result<bool, Error> {
+        let res = self
+            .signers_dict
+            .get(&signer)
+            .ok_or(Error::NoValueInSignersDict)?;
+        Ok(res)
+    }
+
+    // function to set how many distinct signers are required to authorize a withdrawal
+    pub fn set_threshold(&self, threshold: u32) -> Result<(), Error> {
+        if threshold == 0 {
+            return Err(Error::ZeroThreshold);
+        }
+        set_key(SIGNERS_THRESHOLD, threshold);
+        Ok(())
+    }
+
+    pub fn get_threshold(&self) -> u32 {
+        get_key::<u32>(SIGNERS_THRESHOLD).unwrap_or(1)
+    }
+
+    // name of the Casper network the pool was installed on, part of the withdrawal signing domain
+    pub fn get_chain_name(&self) -> Result<String, Error> {
+        get_key::<String>(CASPER_CHAIN_NAME).ok_or(Error::MissingChainName)
+    }
+
+    // function to pause an operation
+    pub fn pause(&self, operation: &str) -> Result<(), Error> {
+        check_operation(operation)?;
+        self.paused_operations_dict.set(operation, true);
+        Ok(())
+    }
+
+    // function to unpause an operation
+    pub fn unpause(&self, operation: &str) -> Result<(), Error> {
+        check_operation(operation)?;
+        self.paused_operations_dict.remove(operation);
+        Ok(())
+    }
+
+    pub fn is_paused(&self, operation: &str) -> bool {
+        self.paused_operations_dict.get_or_default(operation)
+    }
+
+    pub fn check_not_paused(&self, operation: &str) -> Result<(), Error> {
+        if self.is_paused(operation) {
+            return Err(Error::Paused);
+        }
+        Ok(())
+    }
+
+    // function to disable a registered token, only remove_liquidity keeps working for it
+    pub fn disable_token(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+    ) -> Result<(), Error> {
+        self.get_token_name(token_contract_package_hash)?;
+        self.disabled_tokens_dict
+            .set(&token_contract_package_hash, true);
+        Ok(())
+    }
+
+    // function to enable a previously disabled token
+    pub fn enable_token(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+    ) -> Result<(), Error> {
+        self.get_token_name(token_contract_package_hash)?;
+        self.disabled_tokens_dict
+            .remove(&token_contract_package_hash);
+        Ok(())
+    }
+
+    pub fn is_token_disabled(&self, token_contract_package_hash: ContractPackageHash) -> bool {
+        self.disabled_tokens_dict
+            .get_or_default(&token_contract_package_hash)
+    }
+
+    pub fn check_token_enabled(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+    ) -> Result<(), Error> {
+        if self.is_token_disabled(token_contract_package_hash) {
+            return Err(Error::TokenDisabled);
+        }
+        Ok(())
+    }
+
+    // function to get the status of a registered token
+    pub fn get_token_status(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+    ) -> Result<String, Error> {
+        self.get_token_name(token_contract_package_hash)?;
+        if self.is_token_disabled(token_contract_package_hash) {
+            Ok(TOKEN_STATUS_DISABLED.to_string())
+        } else {
+            Ok(TOKEN_STATUS_ACTIVE.to_string())
+        }
+    }
+
+    // function to get the name a token was registered with in allow_target
+    pub fn get_token_name(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+    ) -> Result<String, Error> {
+        self.token_contract_package_hash_dict_name
+            .get(&token_contract_package_hash)
+            .ok_or(Error::NoTokenInTokenContractPackageHashDict)
+    }
+
+    // function to list at most `limit` tokens registered with allow_target from `offset` on
+    pub fn list_tokens(&self, offset: u32, limit: u32) -> Vec<ContractPackageHash> {
+        self.token_contract_package_hash_dict_name
+            .keys(offset, limit)
+    }
+
+    // function to select the interface a registered token is called through, it can only be
+    // changed as long as the pool holds none of the token
+    pub fn set_token_standard(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        token_standard: TokenStandard,
+    ) -> Result<(), Error> {
+        self.get_token_name(token_contract_package_hash)?;
+        if !self
+            .get_total_liquidity(token_contract_package_hash)
+            .is_zero()
+            || !self
+                .get_collected_fees(token_contract_package_hash)
+                .is_zero()
+        {
+            return Err(Error::TokenStandardLocked);
+        }
+        self.token_standards_dict.set(
+            &token_contract_package_hash,
+            token_standard.name().to_string(),
         );
+        Ok(())
+    }
 
+    pub fn get_token_standard(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+    ) -> TokenStandard {
+        self.token_standards_dict
+            .get(&token_contract_package_hash)
+            .map(|name| TokenStandard::from_name(&name).unwrap_or_revert())
+            .unwrap_or(TokenStandard::Erc20)
+    }
+
+    // function to credit a registered token by the change of the pool balance it causes, for
+    // tokens which charge a fee on transfer or rebase
+    pub fn set_balance_delta(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        balance_delta: bool,
+    ) -> Result<(), Error> {
+        if token_contract_package_hash == NATIVE_TOKEN {
+            return Err(Error::NativeTokenBalanceDelta);
+        }
+        self.get_token_name(token_contract_package_hash)?;
+        if balance_delta {
+            self.balance_delta_tokens_dict
+                .set(&token_contract_package_hash, true);
+        } else {
+            self.balance_delta_tokens_dict
+                .remove(&token_contract_package_hash);
+        }
         Ok(())
     }
 
-    // generic function to handle the case of a client and a contract when adding liquidity
-    pub fn add_liquidity_generic(
+    pub fn is_balance_delta(&self, token_contract_package_hash: ContractPackageHash) -> bool {
+        self.balance_delta_tokens_dict
+            .get_or_default(&token_contract_package_hash)
+    }
+
+    // function to swap tokens from different pools, returns the amount received and the fee
+    pub fn swap(
         &self,
-        token_contract_hash: String,
-        client: String,
+        from_address: Address,
+        token_contract_package_hash: ContractPackageHash,
+        target_token: String,
         amount: U256,
-        dict: &Dict,
-    ) {
-        if let Some(clients_dict_address) = dict.get::<String>(token_contract_hash.as_str()) {
-            let clients_dict = Dict::instance(clients_dict_address.as_str());
-            if let Some(client_amount) = clients_dict.get::<U256>(client.as_str()) {
-                clients_dict.set(client.as_str(), client_amount + amount)
-            } else {
-                clients_dict.set(client.as_str(), amount)
+        target_network: U256,
+        purse: Option<URef>,
+    ) -> Result<(U256, U256), Error> {
+        self.get_token_name(token_contract_package_hash)?;
+        self.check_token_enabled(token_contract_package_hash)?;
+        let dict_target_token =
+            self.get_allowed_target(token_contract_package_hash, target_network)?;
+        if dict_target_token != target_token {
+            return Err(Error::DictTargetTokenNotEqualTargetToken);
+        }
+        let (min_amount, max_amount) =
+            self.get_swap_limits_of(token_contract_package_hash, target_network);
+        if amount < min_amount {
+            return Err(Error::SwapAmountTooLow);
+        }
+        if amount > max_amount {
+            return Err(Error::SwapAmountTooHigh);
+        }
+        let amount = match purse {
+            Some(purse) => {
+                self.pay_me_from_purse(purse, amount);
+                amount
+            }
+            None if token_contract_package_hash == NATIVE_TOKEN => {
+                return Err(Error::NativeTokenNeedsPurse)
+            }
+            None => self.pay_me(token_contract_package_hash, from_address, amount)?,
+        };
+        let fee = amount
+            .checked_mul(U256::from(
+                self.get_fee(token_contract_package_hash, target_network),
+            ))
+            .ok_or(Error::FeeOverflow)?
+            / U256::from(FEE_DENOMINATOR);
+        let rewards = self.distribute_rewards(token_contract_package_hash, fee);
+        self.add_collected_fees(token_contract_package_hash, fee - rewards);
+        self.add_total_liquidity(token_contract_package_hash, amount - fee);
+        Ok((amount, fee))
+    }
+
+    // function to allow target for swap, the first target of a token registers it under
+    // `token_name`
+    pub fn allow_target(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        token_name: String,
+        target_token: String,
+        target_network: U256,
+    ) -> Result<(), Error> {
+        if let Some(token_name_from_dict) = self
+            .token_contract_package_hash_dict_name
+            .get(&token_contract_package_hash)
+        {
+            if token_name != token_name_from_dict {
+                return Err(Error::WrongTokenName);
+            }
+            if self
+                .allowed_targets_dict
+                .contains(&token_contract_package_hash, &target_network)
+            {
+                return Err(Error::AlreadyInThisTargetTokenDict);
             }
         } else {
-            let client_dict_name = token_contract_hash.as_str();
-            Dict::init(client_dict_name);
-            let clients_dict = Dict::instance(client_dict_name);
-            clients_dict.set(client.as_str(), amount);
-            dict.set(token_contract_hash.as_str(), client_dict_name);
+            self.token_contract_package_hash_dict_name
+                .set(&token_contract_package_hash, token_name);
         }
+        self.allowed_targets_dict
+            .set(&token_contract_package_hash, &target_network, target_token);
+
+        self.add_target_network(token_contract_package_hash, target_network);
+        Ok(())
     }
 
-    // remove liquidity from the pool
-    pub fn remove_liquidity(
+    // function to remove a target network from the allowed targets of a token
+    pub fn disallow_target(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        target_network: U256,
+    ) -> Result<(), Error> {
+        self.get_allowed_target(token_contract_package_hash, target_network)?;
+        self.allowed_targets_dict
+            .remove(&token_contract_package_hash, &target_network);
+        self.swap_limits_dict
+            .remove(&min_amount_key(token_contract_package_hash, target_network));
+        self.swap_limits_dict
+            .remove(&max_amount_key(token_contract_package_hash, target_network));
+        self.remove_target_network(token_contract_package_hash, target_network);
+        Ok(())
+    }
+
+    // function to replace the target token of an already allowed target network
+    pub fn update_target(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        target_token: String,
+        target_network: U256,
+    ) -> Result<(), Error> {
+        self.get_allowed_target(token_contract_package_hash, target_network)?;
+        self.allowed_targets_dict
+            .set(&token_contract_package_hash, &target_network, target_token);
+        Ok(())
+    }
+
+    // function to set the fee in basis points charged on swaps of a token
+    pub fn set_fee(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        fee_bps: u32,
+    ) -> Result<(), Error> {
+        check_fee(fee_bps)?;
+        self.get_token_name(token_contract_package_hash)?;
+        self.fees_dict.set(&token_contract_package_hash, fee_bps);
+        Ok(())
+    }
+
+    // function to set a fee for swaps of a token to one target network, overriding the token fee
+    pub fn set_target_network_fee(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        target_network: U256,
+        fee_bps: u32,
+    ) -> Result<(), Error> {
+        check_fee(fee_bps)?;
+        self.get_token_name(token_contract_package_hash)?;
+        self.target_network_fees_dict
+            .set(&token_contract_package_hash, &target_network, fee_bps);
+        Ok(())
+    }
+
+    pub fn get_fee(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        target_network: U256,
+    ) -> u32 {
+        self.target_network_fees_dict
+            .get(&token_contract_package_hash, &target_network)
+            .or_else(|| self.fees_dict.get(&token_contract_package_hash))
+            .unwrap_or(0)
+    }
+
+    // function to allow at most `limit` tokens to be withdrawn per rolling window of `window` ms
+    pub fn set_rate_limit(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        limit: U256,
+        window: u64,
+    ) -> Result<(), Error> {
+        self.get_token_name(token_contract_package_hash)?;
+        if window == 0 {
+            return Err(Error::ZeroRateLimitWindow);
+        }
+        self.rate_limits_dict
+            .set(&token_contract_package_hash, (limit, window));
+        self.rate_limit_capacities_dict.set(
+            &token_contract_package_hash,
+            (limit, u64::from(runtime::get_blocktime())),
+        );
+        Ok(())
+    }
+
+    pub fn remove_rate_limit(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+    ) -> Result<(), Error> {
+        self.rate_limits_dict
+            .take(&token_contract_package_hash)
+            .ok_or(Error::NoRateLimit)?;
+        self.rate_limit_capacities_dict
+            .remove(&token_contract_package_hash);
+        Ok(())
+    }
+
+    pub fn consume_rate_limit(
         &self,
         token_contract_package_hash: ContractPackageHash,
-        client_address: Address,
         amount: U256,
     ) -> Result<(), Error> {
-        let client_string: String = TryInto::try_into(client_address)?;
-        self.pay_from_me(token_contract_package_hash, client_address, amount);
-        self.del_liquidity_generic_from_dict(
-            token_contract_package_hash.to_formatted_string(),
-            client_string,
-            amount,
-            self.get_dict(client_address)?,
-        )?;
+        if let Some((limit, window)) = self.rate_limits_dict.get(&token_contract_package_hash) {
+            let remaining = self
+                .get_rate_limit_capacity(token_contract_package_hash, limit, window)?
+                .checked_sub(amount)
+                .ok_or(Error::RateLimitExceeded)?;
+            self.rate_limit_capacities_dict.set(
+                &token_contract_package_hash,
+                (remaining, u64::from(runtime::get_blocktime())),
+            );
+        }
+        Ok(())
+    }
+
+    // gives back the capacity consumed by a withdrawal that is never paid out
+    pub fn refund_rate_limit(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        amount: U256,
+    ) -> Result<(), Error> {
+        if let Some((limit, window)) = self.rate_limits_dict.get(&token_contract_package_hash) {
+            let capacity = self
+                .get_rate_limit_capacity(token_contract_package_hash, limit, window)?
+                .saturating_add(amount)
+                .min(limit);
+            self.rate_limit_capacities_dict.set(
+                &token_contract_package_hash,
+                (capacity, u64::from(runtime::get_blocktime())),
+            );
+        }
+        Ok(())
+    }
+
+    // the remaining capacity refills linearly, reaching the full limit after a whole window
+    fn get_rate_limit_capacity(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        limit: U256,
+        window: u64,
+    ) -> Result<U256, Error> {
+        let (capacity, last_update) = self
+            .rate_limit_capacities_dict
+            .get(&token_contract_package_hash)
+            .unwrap_or((limit, 0));
+        let elapsed = u64::from(runtime::get_blocktime()).saturating_sub(last_update);
+        if elapsed >= window {
+            return Ok(limit);
+        }
+        let refill = limit
+            .checked_mul(U256::from(elapsed))
+            .ok_or(Error::RateLimitOverflow)?
+            / U256::from(window);
+        Ok(limit.min(capacity.saturating_add(refill)))
+    }
+
+    // function to time lock withdrawals of a token above `threshold` for `delay` ms
+    pub fn set_withdrawal_delay(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        threshold: U256,
+        delay: u64,
+    ) -> Result<(), Error> {
+        self.get_token_name(token_contract_package_hash)?;
+        if threshold.is_zero() {
+            return Err(Error::ZeroWithdrawalThreshold);
+        }
+        if delay == 0 {
+            return Err(Error::ZeroWithdrawalDelay);
+        }
+        self.withdrawal_delays_dict
+            .set(&token_contract_package_hash, (threshold, delay));
+        Ok(())
+    }
+
+    // returns the release time of a withdrawal that has to be time locked
+    pub fn get_release_time(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        amount: U256,
+    ) -> Option<u64> {
+        self.withdrawal_delays_dict
+            .get(&token_contract_package_hash)
+            .filter(|(threshold, _)| amount > *threshold)
+            .map(|(_, delay)| u64::from(runtime::get_blocktime()).saturating_add(delay))
+    }
+
+    pub fn queue_withdrawal(&self, message_hash: &str, pending_withdrawal: PendingWithdrawal) {
+        self.pending_withdrawals_dict
+            .set(message_hash, pending_withdrawal);
+    }
+
+    pub fn get_pending_withdrawal(&self, message_hash: &str) -> Result<PendingWithdrawal, Error> {
+        self.pending_withdrawals_dict
+            .get(message_hash)
+            .ok_or(Error::NoPendingWithdrawal)
+    }
+
+    pub fn remove_pending_withdrawal(&self, message_hash: &str) {
+        self.pending_withdrawals_dict.remove(message_hash);
+    }
+
+    // function to check that a withdrawal can be paid out of the liquidity of the recipient, so
+    // it can be rejected before its message hash is used
+    pub fn check_payout(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        recipient: Address,
+        amount: U256,
+    ) -> Result<(), Error> {
+        if self.get_lp_token(token_contract_package_hash).is_none()
+            && !self
+                .get_dict(recipient)?
+                .contains(&token_contract_package_hash, &recipient)
+        {
+            return Err(Error::ClientDoesNotHaveAnyKindOfLiquidity);
+        }
+        if self.get_liquidity_added_by_client(token_contract_package_hash, recipient)? < amount
+            || self.get_total_liquidity(token_contract_package_hash) < amount
+        {
+            return Err(Error::CheckedSubFail);
+        }
+        if self.get_pool_balance(token_contract_package_hash) < amount {
+            return Err(Error::InsufficientPoolBalance);
+        }
+        if token_contract_package_hash == NATIVE_TOKEN && recipient.as_account_hash().is_none() {
+            return Err(Error::NativeRecipientNotAccount);
+        }
+        Ok(())
+    }
+
+    // function to pay out a withdrawal, deducting it from the liquidity of the recipient
+    pub fn pay_withdrawal(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        recipient: Address,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self.sub_total_liquidity(token_contract_package_hash, amount)?;
+        self.deduct_liquidity(token_contract_package_hash, recipient, amount)?;
+        self.pay_from_me(token_contract_package_hash, recipient, amount);
+        Ok(())
+    }
+
+    // function to pay out a relayed withdrawal, the relayer fee out of the amount goes to the
+    // relayer and the rest to the recipient
+    pub fn pay_relayed_withdrawal(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        recipient: Address,
+        relayer: Address,
+        amount: U256,
+        relayer_fee: U256,
+    ) -> Result<(), Error> {
+        let recipient_amount = amount
+            .checked_sub(relayer_fee)
+            .ok_or(Error::RelayerFeeTooHigh)?;
+        self.sub_total_liquidity(token_contract_package_hash, amount)?;
+        self.deduct_liquidity(token_contract_package_hash, recipient, amount)?;
+        self.pay_from_me(token_contract_package_hash, recipient, recipient_amount);
+        if !relayer_fee.is_zero() {
+            self.pay_from_me(token_contract_package_hash, relayer, relayer_fee);
+        }
+        Ok(())
+    }
+
+    // function to get the liquidity held by the pool for a token, swapped amounts included and
+    // fees excluded
+    pub fn get_total_liquidity(&self, token_contract_package_hash: ContractPackageHash) -> U256 {
+        self.total_liquidity_dict
+            .get_or_default(&token_contract_package_hash)
+    }
+
+    fn add_total_liquidity(&self, token_contract_package_hash: ContractPackageHash, amount: U256) {
+        self.total_liquidity_dict
+            .update_with(&token_contract_package_hash, |total_liquidity| {
+                total_liquidity + amount
+            });
+    }
+
+    fn sub_total_liquidity(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let total_liquidity = self
+            .get_total_liquidity(token_contract_package_hash)
+            .checked_sub(amount)
+            .ok_or(Error::CheckedSubFail)?;
+        self.total_liquidity_dict
+            .set(&token_contract_package_hash, total_liquidity);
+        Ok(())
+    }
+
+    // function to get the balance of a token actually held by the pool
+    pub fn get_pool_balance(&self, token_contract_package_hash: ContractPackageHash) -> U256 {
+        if token_contract_package_hash == NATIVE_TOKEN {
+            let balance = system::get_purse_balance(self.pool_purse())
+                .unwrap_or_revert_with(Error::MissingPoolPurse);
+            return from_motes(balance);
+        }
+        self.balance_of(token_contract_package_hash, self.bridge_pool_address())
+    }
+
+    pub fn set_fee_recipient(&self, fee_recipient: Address) {
+        set_key(FEE_RECIPIENT, fee_recipient);
+    }
+
+    pub fn get_fee_recipient(&self) -> Result<Address, Error> {
+        get_key(FEE_RECIPIENT).ok_or(Error::NoFeeRecipient)
+    }
+
+    fn add_collected_fees(&self, token_contract_package_hash: ContractPackageHash, fee: U256) {
+        self.collected_fees_dict
+            .update_with(&token_contract_package_hash, |collected_fees| {
+                collected_fees + fee
+            });
+    }
+
+    pub fn get_collected_fees(&self, token_contract_package_hash: ContractPackageHash) -> U256 {
+        self.collected_fees_dict
+            .get_or_default(&token_contract_package_hash)
+    }
+
+    // function to pay the collected fees of a token out to the fee recipient
+    pub fn withdraw_fees(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+    ) -> Result<(Address, U256), Error> {
+        let fee_recipient = self.get_fee_recipient()?;
+        let collected_fees = self
+            .collected_fees_dict
+            .take(&token_contract_package_hash)
+            .unwrap_or_default();
+        self.pay_from_me(token_contract_package_hash, fee_recipient, collected_fees);
+        Ok((fee_recipient, collected_fees))
+    }
+
+    // function to set the range of amounts that can be swapped to a target network
+    pub fn set_swap_limits(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        target_network: U256,
+        min_amount: U256,
+        max_amount: U256,
+    ) -> Result<(), Error> {
+        if min_amount > max_amount {
+            return Err(Error::InvalidSwapLimits);
+        }
+        self.get_allowed_target(token_contract_package_hash, target_network)?;
+        self.swap_limits_dict.set(
+            &min_amount_key(token_contract_package_hash, target_network),
+            min_amount,
+        );
+        self.swap_limits_dict.set(
+            &max_amount_key(token_contract_package_hash, target_network),
+            max_amount,
+        );
         Ok(())
     }
 
-    // generic function to handle the case of a client and a contract when removing liquidity
-    pub fn del_liquidity_generic_from_dict(
+    // function to get the (min, max) amounts that can be swapped to a target network
+    pub fn get_swap_limits(
         &self,
-        token_contract_hash: String,
-        client: String,
-        amount: U256,
-        dict: &Dict,
-    ) -> Result<(), Error> {
-        if let Some(clients_dict_address) = dict.get::<String>(token_contract_hash.as_str()) {
-            let clients_dict = Dict::instance(clients_dict_address.as_str());
-            if let Some(client_amount) = clients_dict.get::<U256>(client.as_str()) {
-                if let Some(new_amount) = client_amount.checked_sub(amount) {
-                    clients_dict.set(client.as_str(), new_amount);
-                    Ok(())
-                } else {
-                    Err(Error::CheckedSubFail)
-                }
-            } else {
-                Err(Error::ClientDoesNotHaveSpecificKindOfLiquidity)
-            }
-        } else {
-            Err(Error::ClientDoesNotHaveAnyKindOfLiquidity)
-        }
+        token_contract_package_hash: ContractPackageHash,
+        target_network: U256,
+    ) -> Result<(U256, U256), Error> {
+        self.get_allowed_target(token_contract_package_hash, target_network)?;
+        Ok(self.get_swap_limits_of(token_contract_package_hash, target_network))
     }
 
-    // function to add signer
-    pub fn add_signer(&self, signer: String) {
-        self.signers_dict.set(&signer, true)
+    // without limits any amount can be swapped
+    fn get_swap_limits_of(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        target_network: U256,
+    ) -> (U256, U256) {
+        let min_amount = self
+            .swap_limits_dict
+            .get(&min_amount_key(token_contract_package_hash, target_network))
+            .unwrap_or_else(U256::zero);
+        let max_amount = self
+            .swap_limits_dict
+            .get(&max_amount_key(token_contract_package_hash, target_network))
+            .unwrap_or_else(U256::max_value);
+        (min_amount, max_amount)
     }
 
-    // function to remvoe signer
-    pub fn remove_signer(&self, signer: String) {
-        self.signers_dict.remove::<bool>(&signer)
+    // function to get the target token allowed for a target network
+    pub fn get_allowed_target(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        target_network: U256,
+    ) -> Result<String, Error> {
+        self.get_token_name(token_contract_package_hash)?;
+        self.allowed_targets_dict
+            .get(&token_contract_package_hash, &target_network)
+            .ok_or(Error::NoTargetNetworkDictForThisToken)
     }
 
-    pub fn check_signer(&self, signer: String) -> Result<bool, Error> {
-        let res = self
-            .signers_dict
-            .get::<bool>(&signer)
-            .ok_or(Error::NoValueInSignersDict)?;
-        Ok(res)
+    // function to list the target networks allowed for a token
+    pub fn list_target_networks(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+    ) -> Result<Vec<U256>, Error> {
+        self.get_token_name(token_contract_package_hash)?;
+        let count = self
+            .target_network_counts_dict
+            .get_or_default(&token_contract_package_hash);
+        (0..count)
+            .map(|index| {
+                self.target_networks_dict
+                    .get(&token_contract_package_hash, &index)
+                    .ok_or(Error::NoTargetNetworkDictForThisToken)
+            })
+            .collect()
     }
 
-    // function to swap tokens from different pools
-    pub fn swap(
+    // the target networks of a token are stored as a list of (token, index) -> network entries
+    // with its length under the token, plus a (token, network) -> index entry for removal
+    fn add_target_network(
         &self,
-        from_address: Address,
         token_contract_package_hash: ContractPackageHash,
-        target_token: String,
-        amount: U256,
         target_network: U256,
-    ) -> Result<(), Error> {
-        let token_contract_package_hash_string = token_contract_package_hash.to_string();
-        if let Some(token_name_from_dict) = self
-            .token_contract_package_hash_dict_name
-            .get::<String>(token_contract_package_hash_string.as_str())
-        {
-            if let Some(target_token_dict_address) = self
-                .allowed_targets_dict
-                .get::<String>(&(ALLOWED_TARGETS_DICT.to_owned() + token_name_from_dict.as_str()))
-            {
-                let target_token_dict = Dict::instance(target_token_dict_address.as_str());
-                if let Some(dict_target_token) =
-                    target_token_dict.get::<String>(&target_network.to_string())
-                {
-                    if dict_target_token != target_token {
-                        return Err(Error::DictTargetTokenNotEqualTargetToken);
-                    }
-                } else {
-                    return Err(Error::NoTargetNetworkDictForThisToken);
-                }
-            } else {
-                return Err(Error::NoTargetTokenInAllowedTargetsDict);
-            }
-            self.pay_me(token_contract_package_hash, from_address, amount);
-            Ok(())
-        } else {
-            Err(Error::NoTokenInTokenContractPackageHashDict)
-        }
+    ) {
+        let count = self
+            .target_network_counts_dict
+            .get_or_default(&token_contract_package_hash);
+        self.target_networks_dict
+            .set(&token_contract_package_hash, &count, target_network);
+        self.target_network_indexes_dict
+            .set(&token_contract_package_hash, &target_network, count);
+        self.target_network_counts_dict
+            .set(&token_contract_package_hash, count + 1);
     }
 
-    // function to allow target for swap
-    pub fn allow_target(
+    // removes a target network by moving the last entry of the list into its place
+    fn remove_target_network(
         &self,
         token_contract_package_hash: ContractPackageHash,
-        token_name: String,
-        target_token: String,
         target_network: U256,
-    ) -> Result<(), Error> {
-        let token_contract_package_hash_string = token_contract_package_hash.to_string();
-        if let Some(token_name_from_dict) = self
-            .token_contract_package_hash_dict_name
-            .get::<String>(token_contract_package_hash_string.as_str())
+    ) {
+        if let Some(index) = self
+            .target_network_indexes_dict
+            .take(&token_contract_package_hash, &target_network)
         {
-            if token_name != token_name_from_dict {
-                return Err(Error::WrongTokenName);
-            }
-            if let Some(target_token_dict_address) = self
-                .allowed_targets_dict
-                .get::<String>(&(ALLOWED_TARGETS_DICT.to_owned() + token_name.as_str()))
-            {
-                let target_token_dict = Dict::instance(target_token_dict_address.as_str());
-                if target_token_dict
-                    .get::<String>(&target_network.to_string())
-                    .is_some()
-                {
-                    return Err(Error::AlreadyInThisTargetTokenDict);
-                } else {
-                    target_token_dict.set(&target_network.to_string(), target_token)
-                }
-            } else {
-                let target_token_dict_name: &str =
-                    &(ALLOWED_TARGETS_DICT.to_owned() + token_name.as_str());
-                Dict::init(target_token_dict_name);
-
-                let target_token_dict = Dict::instance(target_token_dict_name);
-                target_token_dict.set(target_network.to_string().as_str(), target_token.as_str());
-
-                self.allowed_targets_dict
-                    .set(target_token_dict_name, target_token_dict_name.to_string());
+            let last_index = self
+                .target_network_counts_dict
+                .get(&token_contract_package_hash)
+                .unwrap_or_revert()
+                - 1;
+            let last_network = self
+                .target_networks_dict
+                .take(&token_contract_package_hash, &last_index)
+                .unwrap_or_revert();
+            if index != last_index {
+                self.target_networks_dict
+                    .set(&token_contract_package_hash, &index, last_network);
+                self.target_network_indexes_dict.set(
+                    &token_contract_package_hash,
+                    &last_network,
+                    index,
+                );
             }
-        } else {
-            self.token_contract_package_hash_dict_name.set(
-                token_contract_package_hash_string.as_str(),
-                token_name.clone(),
-            );
-            let target_token_dict_name: &str =
-                &(ALLOWED_TARGETS_DICT.to_owned() + token_name.as_str());
-            Dict::init(target_token_dict_name);
-
-            let target_token_dict = Dict::instance(target_token_dict_name);
-            target_token_dict.set(target_network.to_string().as_str(), target_token.as_str());
-
-            self.allowed_targets_dict
-                .set(target_token_dict_name, target_token_dict_name.to_string());
+            self.target_network_counts_dict
+                .set(&token_contract_package_hash, last_index);
         }
-
-        Ok(())
     }
 
     // pay from any address to any address. Remember to approve the tokens beforehand
@@ -309,42 +1276,286 @@ impl BridgePool {
             RECIPIENT => recipient,
             AMOUNT => amount
         };
-        runtime::call_versioned_contract::<()>(token, None, ERC20_ENTRY_POINT_TRANSFER_FROM, args);
+        self.call_token_transfer(token, ERC20_ENTRY_POINT_TRANSFER_FROM, args);
+    }
+
+    // CEP-18 tokens may return a value from their transfer entry points, which is ignored
+    fn call_token_transfer(
+        &self,
+        token: ContractPackageHash,
+        entry_point: &str,
+        args: RuntimeArgs,
+    ) {
+        match self.get_token_standard(token) {
+            TokenStandard::Erc20 => {
+                runtime::call_versioned_contract::<()>(token, None, entry_point, args);
+            }
+            TokenStandard::Cep18 => {
+                runtime::call_versioned_contract::<IgnoredReturnValue>(
+                    token,
+                    None,
+                    entry_point,
+                    args,
+                );
+            }
+        }
+    }
+
+    // approve the pool to spend `amount` of the owner's tokens with a permit signed by the owner
+    pub fn permit(
+        &self,
+        token: ContractPackageHash,
+        owner: Address,
+        amount: U256,
+        deadline: u64,
+        signature: String,
+    ) -> Result<(), Error> {
+        if token == NATIVE_TOKEN {
+            return Err(Error::NativeTokenNeedsPurse);
+        }
+        // anyone can submit a permit seen in a pending deploy to the token first, its nonce is
+        // spent then but the pool is already approved
+        if self.allowance(token, owner, self.bridge_pool_address()) >= amount {
+            return Ok(());
+        }
+        let args = runtime_args! {
+            OWNER => owner,
+            SPENDER => self.bridge_pool_address(),
+            AMOUNT => amount,
+            DEADLINE => deadline,
+            SIGNATURE => signature
+        };
+        runtime::call_versioned_contract::<()>(token, None, ERC20_ENTRY_POINT_PERMIT, args);
+        Ok(())
     }
 
     // pay from any address to this contract. Remember to approve the tokens beforehand
-    fn pay_me(&self, token: ContractPackageHash, spender: Address, amount: U256) {
+    fn pay_me(
+        &self,
+        token: ContractPackageHash,
+        spender: Address,
+        amount: U256,
+    ) -> Result<U256, Error> {
+        self.pay_to_pool(token, spender, self.bridge_pool_address(), amount)
+    }
+
+    // pay from any address to the pool and return the amount the pool received, which is the
+    // change of its balance for tokens in balance delta mode
+    fn pay_to_pool(
+        &self,
+        token: ContractPackageHash,
+        owner: Address,
+        pool: Address,
+        amount: U256,
+    ) -> Result<U256, Error> {
+        if !self.is_balance_delta(token) {
+            self.pay_to(token, owner, pool, amount);
+            return Ok(amount);
+        }
+        let balance_before = self.balance_of(token, pool);
+        self.pay_to(token, owner, pool, amount);
+        let received = self
+            .balance_of(token, pool)
+            .checked_sub(balance_before)
+            .ok_or(Error::NothingReceived)?;
+        if received.is_zero() {
+            return Err(Error::NothingReceived);
+        }
+        Ok(received)
+    }
+
+    fn bridge_pool_address(&self) -> Address {
         let bridge_pool_contract_package_hash = runtime::get_key(BRIDGE_POOL_CONTRACT_PACKAGE_HASH)
             .unwrap_or_revert_with(Error::MissingContractPackageHash)
             .into_hash()
             .map(ContractPackageHash::new)
             .unwrap_or_revert_with(Error::InvalidContractPackageHash);
-        self.pay_to(
-            token,
-            spender,
-            crate::address::Address::ContractPackage(bridge_pool_contract_package_hash),
-            amount,
-        )
+        crate::address::Address::ContractPackage(bridge_pool_contract_package_hash)
+    }
+
+    // pay native CSPR from a purse of the caller to the pool purse
+    fn pay_me_from_purse(&self, purse: URef, amount: U256) {
+        system::transfer_from_purse_to_purse(purse, self.pool_purse(), to_motes(amount), None)
+            .unwrap_or_revert();
+    }
+
+    fn pool_purse(&self) -> URef {
+        runtime::get_key(POOL_PURSE)
+            .and_then(Key::into_uref)
+            .unwrap_or_revert_with(Error::MissingPoolPurse)
     }
 
+    // native CSPR is paid from the pool purse, and can only be paid to accounts
     fn pay_from_me(&self, token: ContractPackageHash, recipient: Address, amount: U256) {
+        if token == NATIVE_TOKEN {
+            let account_hash = *recipient
+                .as_account_hash()
+                .unwrap_or_revert_with(Error::NativeRecipientNotAccount);
+            system::transfer_from_purse_to_account(
+                self.pool_purse(),
+                account_hash,
+                to_motes(amount),
+                None,
+            )
+            .unwrap_or_revert();
+            return;
+        }
         let args = runtime_args! {
             RECIPIENT => recipient,
             AMOUNT => amount
         };
-        runtime::call_versioned_contract::<()>(token, None, ERC20_ENTRY_POINT_TRANSFER, args);
+        self.call_token_transfer(token, ERC20_ENTRY_POINT_TRANSFER, args);
     }
 
-    pub fn get_dict(&self, client_address: Address) -> Result<&Dict, Error> {
+    pub fn get_dict(
+        &self,
+        client_address: Address,
+    ) -> Result<&NestedDict<ContractPackageHash, Address, U256>, Error> {
         match client_address {
             Address::Account(_) => Ok(&self.account_hash_liquidities_dict),
             Address::ContractPackage(_) => Ok(&self.hash_addr_liquidities_dict),
             Address::ContractHash(_) => Err(Error::UnexpectedContractHash),
         }
     }
+
+    // function to move the entries the first release wrote under string keys to their typed
+    // keys, dictionaries cannot be iterated so the tokens, their target networks, the liquidity
+    // providers and the signers to migrate are passed in, and migrating an entry twice does nothing
+    pub fn migrate_storage(
+        &self,
+        tokens: Vec<ContractPackageHash>,
+        target_networks: Vec<U256>,
+        providers: Vec<Address>,
+        signers: Vec<String>,
+    ) -> Result<(), Error> {
+        for token_contract_package_hash in tokens {
+            self.migrate_token(token_contract_package_hash, &target_networks);
+            for client_address in providers.iter() {
+                self.migrate_provider(token_contract_package_hash, *client_address)?;
+            }
+        }
+        for signer in signers {
+            self.signers_dict.rekey(&signer, &signer);
+        }
+        Ok(())
+    }
+
+    fn migrate_token(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        target_networks: &[U256],
+    ) {
+        let token = &token_contract_package_hash;
+        self.token_contract_package_hash_dict_name
+            .rekey(&token_contract_package_hash.to_string(), token);
+        // the target tokens lived in a dictionary of their own named after the token name, keyed
+        // on the target network
+        let target_token_dict = self
+            .token_contract_package_hash_dict_name
+            .get(token)
+            .and_then(|token_name| {
+                self.allowed_targets_dict
+                    .as_dict()
+                    .get::<String>(&(ALLOWED_TARGETS_DICT.to_owned() + token_name.as_str()))
+            })
+            .map(|target_token_dict_name| Dict::instance(target_token_dict_name.as_str()));
+        if let Some(target_token_dict) = target_token_dict {
+            for target_network in target_networks {
+                if let Some(target_token) =
+                    take_legacy::<String>(&target_token_dict, &target_network.to_string())
+                {
+                    self.allowed_targets_dict
+                        .set(token, target_network, target_token);
+                    self.add_target_network(token_contract_package_hash, *target_network);
+                }
+            }
+        }
+    }
+
+    fn migrate_provider(
+        &self,
+        token_contract_package_hash: ContractPackageHash,
+        client_address: Address,
+    ) -> Result<(), Error> {
+        let dict = self.get_dict(client_address)?;
+        // the liquidity lived in a dictionary named after the token, keyed on the client string
+        if let Some(clients_dict_name) = dict
+            .as_dict()
+            .get::<String>(&token_contract_package_hash.to_formatted_string())
+        {
+            let client_string: String = TryInto::try_into(client_address)?;
+            let clients_dict = Dict::instance(clients_dict_name.as_str());
+            if let Some(liquidity) = take_legacy::<U256>(&clients_dict, &client_string) {
+                dict.update_with(&token_contract_package_hash, &client_address, |amount| {
+                    amount + liquidity
+                });
+                self.add_total_liquidity(token_contract_package_hash, liquidity);
+                self.update_rewards(token_contract_package_hash, client_address)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// removes the value stored under a string key and returns it when there was one
+fn take_legacy<T: CLTyped + FromBytes + ToBytes>(dict: &Dict, legacy_key: &str) -> Option<T> {
+    let value = dict.get::<T>(legacy_key)?;
+    dict.remove::<T>(legacy_key);
+    Some(value)
+}
+
+fn check_operation(operation: &str) -> Result<(), Error> {
+    if PAUSABLE_OPERATIONS.contains(&operation) {
+        Ok(())
+    } else {
+        Err(Error::UnknownOperation)
+    }
+}
+
+// function to check that a fee is within MAX_FEE_BPS
+fn check_fee(fee_bps: u32) -> Result<(), Error> {
+    if fee_bps > MAX_FEE_BPS {
+        return Err(Error::FeeTooHigh);
+    }
+    Ok(())
+}
+
+// the min and max swap limits of a target network are told apart by a suffix
+fn min_amount_key(
+    token_contract_package_hash: ContractPackageHash,
+    target_network: U256,
+) -> (ContractPackageHash, U256, &'static str) {
+    (
+        token_contract_package_hash,
+        target_network,
+        SWAP_MIN_AMOUNT_SUFFIX,
+    )
+}
+
+fn max_amount_key(
+    token_contract_package_hash: ContractPackageHash,
+    target_network: U256,
+) -> (ContractPackageHash, U256, &'static str) {
+    (
+        token_contract_package_hash,
+        target_network,
+        SWAP_MAX_AMOUNT_SUFFIX,
+    )
+}
+
+// amounts of native CSPR are kept as U256 motes like token amounts
+fn to_motes(amount: U256) -> U512 {
+    let mut bytes = [0u8; 32];
+    amount.to_little_endian(&mut bytes);
+    U512::from_little_endian(&bytes)
+}
+
+fn from_motes(motes: U512) -> U256 {
+    let mut bytes = [0u8; 64];
+    motes.to_little_endian(&mut bytes);
+    U256::from_little_endian(&bytes[..32])
 }
 
-// function to return contract package hash in case it's possible
 pub fn contract_package_hash() -> ContractPackageHash {
     let call_stacks = get_call_stack();
     let last_entry = call_stacks.last().unwrap_or_revert();
@@ -395,6 +1606,7 @@ pub fn emit(event: &BridgePoolEvent) {
             target_network,
             target_address,
             amount,
+            fee,
         } => {
             let mut param = BTreeMap::new();
             param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
@@ -404,10 +1616,31 @@ pub fn emit(event: &BridgePoolEvent) {
             param.insert(TARGET_NETWORK, target_network.to_string());
             param.insert(TARGET_ADDRESS, target_address.clone());
             param.insert(AMOUNT, amount.to_string());
+            param.insert(FEE, fee.to_string());
+            events.push(param);
+        }
+        BridgePoolEvent::FeesWithdrawn {
+            token,
+            recipient,
+            amount,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert(EVENT_TYPE, EVENT_BRIDGE_FEES_WITHDRAWN.to_string());
+            param.insert(TOKEN, token.to_string());
+            param.insert(RECIPIENT, (*recipient).try_into().unwrap());
+            param.insert(AMOUNT, amount.to_string());
+            events.push(param);
+        }
+        BridgePoolEvent::FeeRecipientSet { fee_recipient } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert(EVENT_TYPE, EVENT_BRIDGE_FEE_RECIPIENT_SET.to_string());
+            param.insert(FEE_RECIPIENT, (*fee_recipient).try_into().unwrap());
             events.push(param);
         }
         BridgePoolEvent::TransferBySignature {
-            signer,
+            signers,
             receiver,
             token,
             amount,
@@ -415,12 +1648,156 @@ pub fn emit(event: &BridgePoolEvent) {
             let mut param = BTreeMap::new();
             param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
             param.insert(EVENT_TYPE, EVENT_BRIDGE_TRANSFER_BY_SIGNATURE.to_string());
-            param.insert(SIGNER, signer.clone());
+            param.insert(SIGNERS, signers.join(","));
             param.insert(TOKEN, token.to_string());
             param.insert(RECEIVER, receiver.to_string());
             param.insert(AMOUNT, amount.to_string());
             events.push(param);
         }
+        BridgePoolEvent::BridgePaused { operation } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert(EVENT_TYPE, EVENT_BRIDGE_PAUSED.to_string());
+            param.insert(OPERATION, operation.clone());
+            events.push(param);
+        }
+        BridgePoolEvent::BridgeUnpaused { operation } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert(EVENT_TYPE, EVENT_BRIDGE_UNPAUSED.to_string());
+            param.insert(OPERATION, operation.clone());
+            events.push(param);
+        }
+        BridgePoolEvent::TokenDisabled { token } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert(EVENT_TYPE, EVENT_BRIDGE_TOKEN_DISABLED.to_string());
+            param.insert(TOKEN, token.to_string());
+            events.push(param);
+        }
+        BridgePoolEvent::TokenEnabled { token } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert(EVENT_TYPE, EVENT_BRIDGE_TOKEN_ENABLED.to_string());
+            param.insert(TOKEN, token.to_string());
+            events.push(param);
+        }
+        BridgePoolEvent::TargetDisallowed {
+            token,
+            target_network,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert(EVENT_TYPE, EVENT_BRIDGE_TARGET_DISALLOWED.to_string());
+            param.insert(TOKEN, token.to_string());
+            param.insert(TARGET_NETWORK, target_network.to_string());
+            events.push(param);
+        }
+        BridgePoolEvent::TargetUpdated {
+            token,
+            target_network,
+            target_token,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert(EVENT_TYPE, EVENT_BRIDGE_TARGET_UPDATED.to_string());
+            param.insert(TOKEN, token.to_string());
+            param.insert(TARGET_NETWORK, target_network.to_string());
+            param.insert(TARGET_TOKEN, target_token.clone());
+            events.push(param);
+        }
+        BridgePoolEvent::RewardsClaimed {
+            token,
+            provider,
+            amount,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert(EVENT_TYPE, EVENT_BRIDGE_REWARDS_CLAIMED.to_string());
+            param.insert(TOKEN, token.to_string());
+            param.insert(PROVIDER, (*provider).try_into().unwrap());
+            param.insert(AMOUNT, amount.to_string());
+            events.push(param);
+        }
+        BridgePoolEvent::WithdrawalQueued {
+            message_hash,
+            signers,
+            recipient,
+            token,
+            amount,
+            release_time,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert(EVENT_TYPE, EVENT_BRIDGE_WITHDRAWAL_QUEUED.to_string());
+            param.insert(MESSAGE_HASH, message_hash.clone());
+            param.insert(SIGNERS, signers.join(","));
+            param.insert(RECIPIENT, (*recipient).try_into().unwrap());
+            param.insert(TOKEN, token.to_string());
+            param.insert(AMOUNT, amount.to_string());
+            param.insert(RELEASE_TIME, release_time.to_string());
+            events.push(param);
+        }
+        BridgePoolEvent::RelayerFeePaid {
+            message_hash,
+            relayer,
+            token,
+            amount,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert(EVENT_TYPE, EVENT_BRIDGE_RELAYER_FEE_PAID.to_string());
+            param.insert(MESSAGE_HASH, message_hash.clone());
+            param.insert(RELAYER, (*relayer).try_into().unwrap());
+            param.insert(TOKEN, token.to_string());
+            param.insert(AMOUNT, amount.to_string());
+            events.push(param);
+        }
+        BridgePoolEvent::WithdrawalClaimed {
+            message_hash,
+            recipient,
+            token,
+            amount,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert(EVENT_TYPE, EVENT_BRIDGE_WITHDRAWAL_CLAIMED.to_string());
+            param.insert(MESSAGE_HASH, message_hash.clone());
+            param.insert(RECIPIENT, (*recipient).try_into().unwrap());
+            param.insert(TOKEN, token.to_string());
+            param.insert(AMOUNT, amount.to_string());
+            events.push(param);
+        }
+        BridgePoolEvent::WithdrawalCancelled {
+            message_hash,
+            recipient,
+            token,
+            amount,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert(EVENT_TYPE, EVENT_BRIDGE_WITHDRAWAL_CANCELLED.to_string());
+            param.insert(MESSAGE_HASH, message_hash.clone());
+            param.insert(RECIPIENT, (*recipient).try_into().unwrap());
+            param.insert(TOKEN, token.to_string());
+            param.insert(AMOUNT, amount.to_string());
+            events.push(param);
+        }
+        BridgePoolEvent::WithdrawalVetoed {
+            message_hash,
+            recipient,
+            token,
+            amount,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert(EVENT_TYPE, EVENT_BRIDGE_WITHDRAWAL_VETOED.to_string());
+            param.insert(MESSAGE_HASH, message_hash.clone());
+            param.insert(RECIPIENT, (*recipient).try_into().unwrap());
+            param.insert(TOKEN, token.to_string());
+            param.insert(AMOUNT, amount.to_string());
+            events.push(param);
+        }
     };
 
     for param in events {
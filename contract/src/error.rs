@@ -46,6 +46,43 @@ pub enum Error {
     SignerWrongFormat = 41,
     MessageHashNotEqualToGenerated = 42,
     WrongCaller = 43,
+    DuplicateSigner = 44,
+    NotEnoughSignatures = 45,
+    ZeroThreshold = 46,
+    AuthorizationExpired = 47,
+    MissingChainName = 48,
+    Paused = 49,
+    UnknownOperation = 50,
+    TokenDisabled = 51,
+    FeeTooHigh = 52,
+    NoFeeRecipient = 53,
+    FeeOverflow = 54,
+    SwapAmountTooLow = 55,
+    SwapAmountTooHigh = 56,
+    InvalidSwapLimits = 57,
+    ZeroRateLimitWindow = 58,
+    RateLimitExceeded = 59,
+    RateLimitOverflow = 60,
+    NoPendingWithdrawal = 61,
+    WithdrawalStillLocked = 62,
+    LpTokenAlreadySet = 63,
+    NoLpToken = 64,
+    RewardShareTooHigh = 65,
+    NoRewards = 66,
+    NativeTokenNeedsPurse = 67,
+    NativeRecipientNotAccount = 68,
+    MissingPoolPurse = 69,
+    UnknownTokenStandard = 70,
+    TokenStandardLocked = 71,
+    NothingReceived = 72,
+    NativeTokenBalanceDelta = 73,
+    InvalidPayee = 74,
+    RelayerFeeTooHigh = 75,
+    InsufficientPoolBalance = 77,
+    NotLpToken = 78,
+    NoRateLimit = 79,
+    ZeroWithdrawalThreshold = 80,
+    ZeroWithdrawalDelay = 81,
 }
 
 impl From<Error> for ApiError {
@@ -1,4 +1,4 @@
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use casper_types::{ContractPackageHash, U256};
 
 use crate::address::Address;
@@ -22,13 +22,95 @@ pub enum BridgePoolEvent {
         target_network: U256,
         // client address
         target_address: String,
+        // gross amount pulled from the client, fee included
         amount: U256,
+        fee: U256,
     },
     // event dispatched in case of transfer by signature has happened
     TransferBySignature {
-        signer: String,
+        signers: Vec<String>,
         receiver: String,
         token: ContractPackageHash,
         amount: U256,
     },
+    // event dispatched in case an operation was paused
+    BridgePaused {
+        operation: String,
+    },
+    // event dispatched in case an operation was unpaused
+    BridgeUnpaused {
+        operation: String,
+    },
+    // event dispatched in case a token was disabled
+    TokenDisabled {
+        token: ContractPackageHash,
+    },
+    // event dispatched in case a token was enabled again
+    TokenEnabled {
+        token: ContractPackageHash,
+    },
+    // event dispatched in case collected fees were paid out
+    FeesWithdrawn {
+        token: ContractPackageHash,
+        recipient: Address,
+        amount: U256,
+    },
+    // event dispatched in case the address collected fees are paid to was changed
+    FeeRecipientSet {
+        fee_recipient: Address,
+    },
+    // event dispatched in case a large withdrawal was time locked
+    WithdrawalQueued {
+        message_hash: String,
+        signers: Vec<String>,
+        recipient: Address,
+        token: ContractPackageHash,
+        amount: U256,
+        release_time: u64,
+    },
+    // event dispatched in case a pending withdrawal was paid out
+    WithdrawalClaimed {
+        message_hash: String,
+        recipient: Address,
+        token: ContractPackageHash,
+        amount: U256,
+    },
+    // event dispatched in case the recipient gave up a pending withdrawal
+    WithdrawalCancelled {
+        message_hash: String,
+        recipient: Address,
+        token: ContractPackageHash,
+        amount: U256,
+    },
+    // event dispatched in case an admin vetoed a pending withdrawal
+    WithdrawalVetoed {
+        message_hash: String,
+        recipient: Address,
+        token: ContractPackageHash,
+        amount: U256,
+    },
+    // event dispatched in case a target network was removed for a token
+    TargetDisallowed {
+        token: ContractPackageHash,
+        target_network: U256,
+    },
+    // event dispatched in case the target token of a target network was changed
+    TargetUpdated {
+        token: ContractPackageHash,
+        target_network: U256,
+        target_token: String,
+    },
+    // event dispatched in case a liquidity provider claimed its rewards
+    RewardsClaimed {
+        token: ContractPackageHash,
+        provider: Address,
+        amount: U256,
+    },
+    // event dispatched in case the submitter of a relayed withdrawal was paid its fee
+    RelayerFeePaid {
+        message_hash: String,
+        relayer: Address,
+        token: ContractPackageHash,
+        amount: U256,
+    },
 }
@@ -8,3 +8,6 @@ pub mod data;
 pub mod detail;
 pub mod error;
 pub mod event;
+pub mod pending_withdrawal;
+pub mod signed_withdrawal;
+pub mod token_standard;
@@ -10,19 +10,44 @@ use alloc::{
     collections::BTreeSet,
     string::{String, ToString},
     vec,
+    vec::Vec,
 };
 use bridge_pool::{
+    address::Address,
     bridge_pool_contract::BridgePoolContract,
     consts::{
-        ADMIN_ACCESS_UREF, ADMIN_GROUP, AMOUNT, BRIDGE_POOL_ACCESS_UREF, BRIDGE_POOL_CONTRACT_HASH,
-        BRIDGE_POOL_CONTRACT_PACKAGE_HASH, BRIDGE_POOL_PACKAGE_NAME, CALLER, CHAIN_ID,
-        CONSTRUCTOR_GROUP, CONTRACT_KEY, CONTRACT_VERSION_KEY, ENTRY_POINT_ADD_LIQUIDITY,
-        ENTRY_POINT_ADD_SIGNER, ENTRY_POINT_ALLOW_TARGET, ENTRY_POINT_CHECK_SIGNER,
-        ENTRY_POINT_CONSTRUCTOR, ENTRY_POINT_GET_LIQUIDITY, ENTRY_POINT_REMOVE_LIQUIDITY,
-        ENTRY_POINT_REMOVE_SIGNER, ENTRY_POINT_SWAP, ENTRY_POINT_WITHDRAW_SIGNED, PAYEE, SALT,
-        SIGNATURE, SIGNER, TARGET_ADDRESS, TARGET_NETWORK, TARGET_TOKEN, TOKEN_ADDRESS, TOKEN_NAME,
-        TOKEN_RECIPIENT,
+        ADMIN_ACCESS_UREF, ADMIN_GROUP, AMOUNT, BALANCE_DELTA, BRIDGE_POOL_ACCESS_UREF,
+        BRIDGE_POOL_CONTRACT_HASH, BRIDGE_POOL_CONTRACT_PACKAGE_HASH, BRIDGE_POOL_PACKAGE_NAME,
+        CALLER, CHAIN_ID, CHAIN_NAME, CONSTRUCTOR_GROUP, CONTRACT_KEY, CONTRACT_VERSION_KEY,
+        DEADLINE, DELAY, ENTRY_POINT_ADD_LIQUIDITY, ENTRY_POINT_ADD_LIQUIDITY_NATIVE,
+        ENTRY_POINT_ADD_LIQUIDITY_WITH_PERMIT, ENTRY_POINT_ADD_SIGNER, ENTRY_POINT_ALLOW_TARGET,
+        ENTRY_POINT_CANCEL_WITHDRAWAL, ENTRY_POINT_CHECK_SIGNER, ENTRY_POINT_CLAIM_REWARDS,
+        ENTRY_POINT_CLAIM_WITHDRAWAL, ENTRY_POINT_CONSTRUCTOR, ENTRY_POINT_DISABLE_TOKEN,
+        ENTRY_POINT_DISALLOW_TARGET, ENTRY_POINT_ENABLE_TOKEN, ENTRY_POINT_GET_ALLOWED_TARGET,
+        ENTRY_POINT_GET_BALANCE_DELTA, ENTRY_POINT_GET_LIQUIDITY, ENTRY_POINT_GET_LIQUIDITY_OF,
+        ENTRY_POINT_GET_LP_TOKEN, ENTRY_POINT_GET_PENDING_REWARDS, ENTRY_POINT_GET_POOL_BALANCE,
+        ENTRY_POINT_GET_SWAP_LIMITS, ENTRY_POINT_GET_TOKEN_NAME, ENTRY_POINT_GET_TOKEN_STANDARD,
+        ENTRY_POINT_GET_TOKEN_STATUS, ENTRY_POINT_GET_TOTAL_LIQUIDITY,
+        ENTRY_POINT_LIST_LIQUIDITY_PROVIDERS, ENTRY_POINT_LIST_SIGNERS,
+        ENTRY_POINT_LIST_TARGET_NETWORKS, ENTRY_POINT_LIST_TOKENS, ENTRY_POINT_MIGRATE_STORAGE,
+        ENTRY_POINT_PAUSE, ENTRY_POINT_REMOVE_LIQUIDITY, ENTRY_POINT_REMOVE_RATE_LIMIT,
+        ENTRY_POINT_REMOVE_SIGNER, ENTRY_POINT_SET_BALANCE_DELTA, ENTRY_POINT_SET_FEE,
+        ENTRY_POINT_SET_FEE_RECIPIENT, ENTRY_POINT_SET_LP_TOKEN, ENTRY_POINT_SET_RATE_LIMIT,
+        ENTRY_POINT_SET_REWARD_SHARE, ENTRY_POINT_SET_SWAP_LIMITS,
+        ENTRY_POINT_SET_TARGET_NETWORK_FEE, ENTRY_POINT_SET_THRESHOLD,
+        ENTRY_POINT_SET_TOKEN_STANDARD, ENTRY_POINT_SET_WITHDRAWAL_DELAY, ENTRY_POINT_SWAP,
+        ENTRY_POINT_SWAP_NATIVE, ENTRY_POINT_SWAP_WITH_PERMIT, ENTRY_POINT_TRANSFER_REWARD_SHARES,
+        ENTRY_POINT_UNPAUSE, ENTRY_POINT_UPDATE_TARGET, ENTRY_POINT_UPGRADE,
+        ENTRY_POINT_VETO_WITHDRAWAL, ENTRY_POINT_WITHDRAW_FEES, ENTRY_POINT_WITHDRAW_SIGNED,
+        ENTRY_POINT_WITHDRAW_SIGNED_BATCH, ENTRY_POINT_WITHDRAW_SIGNED_NATIVE,
+        ENTRY_POINT_WITHDRAW_SIGNED_RELAYED, EXPIRY, FEE_BPS, FEE_RECIPIENT, LIMIT,
+        LP_TOKEN_ADDRESS, MAX_AMOUNT, MESSAGE_HASH, MIN_AMOUNT, OFFSET, OPERATION, OWNER, PAYEE,
+        PROVIDERS, PURSE, RECIPIENT, RELAYER_FEE, REVERT_ON_FAILURE, REWARD_SHARE_BPS, SALT,
+        SIGNATURE, SIGNATURES, SIGNER, SIGNERS, TARGET_ADDRESS, TARGET_NETWORK, TARGET_NETWORKS,
+        TARGET_TOKEN, THRESHOLD, TOKEN_ADDRESS, TOKEN_ADDRESSES, TOKEN_NAME, TOKEN_RECIPIENT,
+        TOKEN_STANDARD, WINDOW, WITHDRAWALS,
     },
+    signed_withdrawal::{SignedWithdrawal, SignedWithdrawalArgs},
 };
 use casper_contract::{
     contract_api::{runtime, storage},
@@ -31,7 +56,7 @@ use casper_contract::{
 use casper_types::RuntimeArgs;
 use casper_types::{
     contracts::{EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, NamedKeys},
-    runtime_args, CLType, CLTyped, CLValue, ContractPackageHash, Parameter, U256,
+    runtime_args, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash, Parameter, U256,
 };
 use casper_types::{Group, Key, URef};
 use contract_utils::{ContractContext, OnChainContractStorage};
@@ -48,8 +73,8 @@ impl ContractContext<OnChainContractStorage> for Contract {
 impl BridgePoolContract<OnChainContractStorage> for Contract {}
 
 impl Contract {
-    fn constructor(&mut self) {
-        BridgePoolContract::init(self);
+    fn constructor(&mut self, chain_name: String) {
+        BridgePoolContract::init(self, chain_name);
     }
 }
 
@@ -61,8 +86,9 @@ pub extern "C" fn constructor() {
         BRIDGE_POOL_CONTRACT_PACKAGE_HASH,
         bridge_pool_contract_package_hash,
     );
+    let chain_name = runtime::get_named_arg::<String>(CHAIN_NAME);
 
-    Contract::default().constructor();
+    Contract::default().constructor(chain_name);
 }
 
 #[no_mangle]
@@ -76,6 +102,69 @@ pub extern "C" fn get_liquidity() {
     runtime::ret(typed_result);
 }
 
+#[no_mangle]
+pub extern "C" fn add_liquidity_native() {
+    let amount = runtime::get_named_arg::<U256>(AMOUNT);
+    let purse = runtime::get_named_arg::<URef>(PURSE);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .add_liquidity_native(amount, purse)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn swap_native() {
+    let amount = runtime::get_named_arg::<U256>(AMOUNT);
+    let target_network = runtime::get_named_arg::<U256>(TARGET_NETWORK);
+    let target_token = runtime::get_named_arg::<String>(TARGET_TOKEN);
+    let target_address = runtime::get_named_arg::<String>(TARGET_ADDRESS);
+    let purse = runtime::get_named_arg::<URef>(PURSE);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .swap_native(amount, target_network, target_token, target_address, purse)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw_signed_native() {
+    let payee = runtime::get_named_arg::<String>(PAYEE);
+    let amount = runtime::get_named_arg::<U256>(AMOUNT);
+    let chain_id = runtime::get_named_arg::<u64>(CHAIN_ID);
+    let salt = runtime::get_named_arg::<String>(SALT);
+    let signatures = runtime::get_named_arg::<Vec<String>>(SIGNATURES);
+    let token_recipient = runtime::get_named_arg::<String>(TOKEN_RECIPIENT);
+    let caller = runtime::get_named_arg::<String>(CALLER);
+    let expiry = runtime::get_named_arg::<u64>(EXPIRY);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .withdraw_signed_native(
+            payee,
+            amount,
+            chain_id,
+            salt,
+            token_recipient,
+            signatures,
+            caller,
+            expiry,
+        )
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_liquidity_of() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let owner = runtime::get_named_arg::<Key>(OWNER);
+    let result = Contract::default()
+        .get_liquidity_of(token_address, owner)
+        .unwrap_or_revert();
+
+    let typed_result = CLValue::from_t(result).unwrap_or_revert();
+    runtime::ret(typed_result);
+}
+
 #[no_mangle]
 pub extern "C" fn add_liquidity() {
     let amount = runtime::get_named_arg::<U256>(AMOUNT);
@@ -89,6 +178,19 @@ pub extern "C" fn add_liquidity() {
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub extern "C" fn add_liquidity_with_permit() {
+    let amount = runtime::get_named_arg::<U256>(AMOUNT);
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let deadline = runtime::get_named_arg::<u64>(DEADLINE);
+    let signature = runtime::get_named_arg::<String>(SIGNATURE);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .add_liquidity_with_permit(amount, token_address, deadline, signature)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn remove_liquidity() {
     let amount = runtime::get_named_arg::<U256>(AMOUNT);
@@ -120,6 +222,30 @@ pub extern "C" fn swap() {
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub extern "C" fn swap_with_permit() {
+    let amount = runtime::get_named_arg::<U256>(AMOUNT);
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let target_network = runtime::get_named_arg::<U256>(TARGET_NETWORK);
+    let target_token = runtime::get_named_arg::<String>(TARGET_TOKEN);
+    let target_address = runtime::get_named_arg::<String>(TARGET_ADDRESS);
+    let deadline = runtime::get_named_arg::<u64>(DEADLINE);
+    let signature = runtime::get_named_arg::<String>(SIGNATURE);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .swap_with_permit(
+            token_address,
+            amount,
+            target_network,
+            target_token,
+            target_address,
+            deadline,
+            signature,
+        )
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn allow_target() {
     let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
@@ -133,6 +259,29 @@ pub extern "C" fn allow_target() {
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub extern "C" fn disallow_target() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let target_network = runtime::get_named_arg::<U256>(TARGET_NETWORK);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .disallow_target(token_address, target_network)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn update_target() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let target_network = runtime::get_named_arg::<U256>(TARGET_NETWORK);
+    let target_token = runtime::get_named_arg::<String>(TARGET_TOKEN);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .update_target(token_address, target_network, target_token)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn withdraw_signed() {
     let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
@@ -140,9 +289,10 @@ pub extern "C" fn withdraw_signed() {
     let amount = runtime::get_named_arg::<U256>(AMOUNT);
     let chain_id = runtime::get_named_arg::<u64>(CHAIN_ID);
     let salt = runtime::get_named_arg::<String>(SALT);
-    let signature = runtime::get_named_arg::<String>(SIGNATURE);
+    let signatures = runtime::get_named_arg::<Vec<String>>(SIGNATURES);
     let token_recipient = runtime::get_named_arg::<String>(TOKEN_RECIPIENT);
     let caller = runtime::get_named_arg::<String>(CALLER);
+    let expiry = runtime::get_named_arg::<u64>(EXPIRY);
     #[allow(clippy::let_unit_value)]
     let ret = Contract::default()
         .withdraw_signed(
@@ -152,8 +302,51 @@ pub extern "C" fn withdraw_signed() {
             chain_id,
             salt,
             token_recipient,
-            signature,
+            signatures,
             caller,
+            expiry,
+        )
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw_signed_relayed() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let payee = runtime::get_named_arg::<String>(PAYEE);
+    let amount = runtime::get_named_arg::<U256>(AMOUNT);
+    let relayer_fee = runtime::get_named_arg::<U256>(RELAYER_FEE);
+    let chain_id = runtime::get_named_arg::<u64>(CHAIN_ID);
+    let salt = runtime::get_named_arg::<String>(SALT);
+    let signatures = runtime::get_named_arg::<Vec<String>>(SIGNATURES);
+    let expiry = runtime::get_named_arg::<u64>(EXPIRY);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .withdraw_signed_relayed(
+            token_address,
+            payee,
+            amount,
+            relayer_fee,
+            chain_id,
+            salt,
+            signatures,
+            expiry,
+        )
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw_signed_batch() {
+    let withdrawals = runtime::get_named_arg::<Vec<SignedWithdrawalArgs>>(WITHDRAWALS);
+    let revert_on_failure = runtime::get_named_arg::<bool>(REVERT_ON_FAILURE);
+    let ret = Contract::default()
+        .withdraw_signed_batch(
+            withdrawals
+                .into_iter()
+                .map(SignedWithdrawal::from)
+                .collect(),
+            revert_on_failure,
         )
         .unwrap_or_revert();
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
@@ -184,121 +377,1076 @@ pub extern "C" fn remove_signer() {
 }
 
 #[no_mangle]
-pub extern "C" fn call() {
-    let bridge_pool_named_keys = NamedKeys::new();
+pub extern "C" fn set_threshold() {
+    let threshold = runtime::get_named_arg::<u32>(THRESHOLD);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .set_threshold(threshold)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    // Create entry points for this contract
-    let mut bridge_pool_entry_points = EntryPoints::new();
+#[no_mangle]
+pub extern "C" fn set_fee() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let fee_bps = runtime::get_named_arg::<u32>(FEE_BPS);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .set_fee(token_address, fee_bps)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    let admin_group = Group::new(ADMIN_GROUP);
+#[no_mangle]
+pub extern "C" fn set_target_network_fee() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let target_network = runtime::get_named_arg::<U256>(TARGET_NETWORK);
+    let fee_bps = runtime::get_named_arg::<u32>(FEE_BPS);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .set_target_network_fee(token_address, target_network, fee_bps)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_CONSTRUCTOR,
-        vec![],
-        <()>::cl_type(),
-        EntryPointAccess::Groups(vec![Group::new(CONSTRUCTOR_GROUP)]),
-        EntryPointType::Contract,
-    ));
+#[no_mangle]
+pub extern "C" fn set_fee_recipient() {
+    let fee_recipient = runtime::get_named_arg::<Address>(FEE_RECIPIENT);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .set_fee_recipient(fee_recipient)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_GET_LIQUIDITY,
-        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
-        CLType::U256,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
+#[no_mangle]
+pub extern "C" fn withdraw_fees() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .withdraw_fees(token_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_ADD_LIQUIDITY,
-        vec![
-            Parameter::new(AMOUNT, U256::cl_type()),
-            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
-            Parameter::new(BRIDGE_POOL_CONTRACT_PACKAGE_HASH, String::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
+#[no_mangle]
+pub extern "C" fn set_rate_limit() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let limit = runtime::get_named_arg::<U256>(LIMIT);
+    let window = runtime::get_named_arg::<u64>(WINDOW);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .set_rate_limit(token_address, limit, window)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_REMOVE_LIQUIDITY,
-        vec![
-            Parameter::new(AMOUNT, U256::cl_type()),
-            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
+#[no_mangle]
+pub extern "C" fn remove_rate_limit() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .remove_rate_limit(token_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_SWAP,
-        vec![
-            Parameter::new(AMOUNT, U256::cl_type()),
-            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
-            Parameter::new(TARGET_NETWORK, U256::cl_type()),
-            Parameter::new(TARGET_TOKEN, String::cl_type()),
-            Parameter::new(TARGET_ADDRESS, String::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
+#[no_mangle]
+pub extern "C" fn set_withdrawal_delay() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let threshold = runtime::get_named_arg::<U256>(THRESHOLD);
+    let delay = runtime::get_named_arg::<u64>(DELAY);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .set_withdrawal_delay(token_address, threshold, delay)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_ALLOW_TARGET,
-        vec![
-            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
-            Parameter::new(TOKEN_NAME, String::cl_type()),
-            Parameter::new(TARGET_NETWORK, U256::cl_type()),
-            Parameter::new(TARGET_TOKEN, String::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Groups(vec![admin_group.clone()]),
-        EntryPointType::Contract,
-    ));
+#[no_mangle]
+pub extern "C" fn claim_withdrawal() {
+    let message_hash = runtime::get_named_arg::<String>(MESSAGE_HASH);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .claim_withdrawal(message_hash)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_WITHDRAW_SIGNED,
-        vec![
-            Parameter::new(PAYEE, String::cl_type()),
-            Parameter::new(CHAIN_ID, u64::cl_type()),
-            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
-            Parameter::new(AMOUNT, U256::cl_type()),
-            Parameter::new(SALT, String::cl_type()),
+#[no_mangle]
+pub extern "C" fn cancel_withdrawal() {
+    let message_hash = runtime::get_named_arg::<String>(MESSAGE_HASH);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .cancel_withdrawal(message_hash)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn veto_withdrawal() {
+    let message_hash = runtime::get_named_arg::<String>(MESSAGE_HASH);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .veto_withdrawal(message_hash)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn pause() {
+    let operation = runtime::get_named_arg::<String>(OPERATION);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default().pause(operation).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn unpause() {
+    let operation = runtime::get_named_arg::<String>(OPERATION);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default().unpause(operation).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn disable_token() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .disable_token(token_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn enable_token() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .enable_token(token_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_token_status() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let ret = Contract::default()
+        .get_token_status(token_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_allowed_target() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let target_network = runtime::get_named_arg::<U256>(TARGET_NETWORK);
+    let ret = Contract::default()
+        .get_allowed_target(token_address, target_network)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_swap_limits() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let target_network = runtime::get_named_arg::<U256>(TARGET_NETWORK);
+    let min_amount = runtime::get_named_arg::<U256>(MIN_AMOUNT);
+    let max_amount = runtime::get_named_arg::<U256>(MAX_AMOUNT);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .set_swap_limits(token_address, target_network, min_amount, max_amount)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_swap_limits() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let target_network = runtime::get_named_arg::<U256>(TARGET_NETWORK);
+    let ret = Contract::default()
+        .get_swap_limits(token_address, target_network)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_lp_token() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let lp_token_address = runtime::get_named_arg::<String>(LP_TOKEN_ADDRESS);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .set_lp_token(token_address, lp_token_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_lp_token() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let ret = Contract::default()
+        .get_lp_token(token_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_reward_share() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let reward_share_bps = runtime::get_named_arg::<u32>(REWARD_SHARE_BPS);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .set_reward_share(token_address, reward_share_bps)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn claim_rewards() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .claim_rewards(token_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_reward_shares() {
+    let owner = runtime::get_named_arg::<Address>(OWNER);
+    let recipient = runtime::get_named_arg::<Address>(RECIPIENT);
+    let amount = runtime::get_named_arg::<U256>(AMOUNT);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .transfer_reward_shares(owner, recipient, amount)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_pending_rewards() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let ret = Contract::default()
+        .get_pending_rewards(token_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_total_liquidity() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let ret = Contract::default()
+        .get_total_liquidity(token_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_pool_balance() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let ret = Contract::default()
+        .get_pool_balance(token_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_token_standard() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let token_standard = runtime::get_named_arg::<String>(TOKEN_STANDARD);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .set_token_standard(token_address, token_standard)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_token_standard() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let ret = Contract::default()
+        .get_token_standard(token_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_balance_delta() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let balance_delta = runtime::get_named_arg::<bool>(BALANCE_DELTA);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .set_balance_delta(token_address, balance_delta)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_balance_delta() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let ret = Contract::default()
+        .get_balance_delta(token_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn upgrade() {
+    let chain_name = runtime::get_named_arg::<String>(CHAIN_NAME);
+    let token_addresses = runtime::get_named_arg::<Vec<String>>(TOKEN_ADDRESSES);
+    let target_networks = runtime::get_named_arg::<Vec<U256>>(TARGET_NETWORKS);
+    let providers = runtime::get_named_arg::<Vec<Address>>(PROVIDERS);
+    let signers = runtime::get_named_arg::<Vec<String>>(SIGNERS);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .upgrade(
+            chain_name,
+            token_addresses,
+            target_networks,
+            providers,
+            signers,
+        )
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn migrate_storage() {
+    let token_addresses = runtime::get_named_arg::<Vec<String>>(TOKEN_ADDRESSES);
+    let target_networks = runtime::get_named_arg::<Vec<U256>>(TARGET_NETWORKS);
+    let providers = runtime::get_named_arg::<Vec<Address>>(PROVIDERS);
+    let signers = runtime::get_named_arg::<Vec<String>>(SIGNERS);
+    #[allow(clippy::let_unit_value)]
+    let ret = Contract::default()
+        .migrate_storage(token_addresses, target_networks, providers, signers)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_token_name() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let ret = Contract::default()
+        .get_token_name(token_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn list_target_networks() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let ret = Contract::default()
+        .list_target_networks(token_address)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn list_signers() {
+    let offset = runtime::get_named_arg::<u32>(OFFSET);
+    let limit = runtime::get_named_arg::<u32>(LIMIT);
+    let ret = Contract::default()
+        .list_signers(offset, limit)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn list_tokens() {
+    let offset = runtime::get_named_arg::<u32>(OFFSET);
+    let limit = runtime::get_named_arg::<u32>(LIMIT);
+    let ret = Contract::default()
+        .list_tokens(offset, limit)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn list_liquidity_providers() {
+    let token_address = runtime::get_named_arg::<String>(TOKEN_ADDRESS);
+    let offset = runtime::get_named_arg::<u32>(OFFSET);
+    let limit = runtime::get_named_arg::<u32>(LIMIT);
+    let ret = Contract::default()
+        .list_liquidity_providers(token_address, offset, limit)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+// adds a new version to the package of an installed pool, which keeps the named keys of the
+// previous version, lets it create its missing storage and migrate its entries, then disables
+// the previous version
+fn upgrade_installed_pool(
+    package_hash: ContractPackageHash,
+    entry_points: EntryPoints,
+    chain_name: String,
+) {
+    let previous_contract_hash = runtime::get_key(BRIDGE_POOL_CONTRACT_HASH)
+        .unwrap_or_revert()
+        .into_hash()
+        .map(ContractHash::new)
+        .unwrap_or_revert();
+
+    let (stored_contract_hash, contract_version) =
+        storage::add_contract_version(package_hash, entry_points, NamedKeys::new());
+
+    let constructor_access: URef =
+        storage::provision_contract_user_group_uref(package_hash, CONSTRUCTOR_GROUP)
+            .unwrap_or_revert();
+
+    let _: () = runtime::call_contract(
+        stored_contract_hash,
+        ENTRY_POINT_UPGRADE,
+        runtime_args! {
+            CHAIN_NAME => chain_name,
+            TOKEN_ADDRESSES => runtime::get_named_arg::<Vec<String>>(TOKEN_ADDRESSES),
+            TARGET_NETWORKS => runtime::get_named_arg::<Vec<U256>>(TARGET_NETWORKS),
+            PROVIDERS => runtime::get_named_arg::<Vec<Address>>(PROVIDERS),
+            SIGNERS => runtime::get_named_arg::<Vec<String>>(SIGNERS),
+        },
+    );
+
+    let mut urefs = BTreeSet::new();
+    urefs.insert(constructor_access);
+    storage::remove_contract_user_group_urefs(package_hash, CONSTRUCTOR_GROUP, urefs)
+        .unwrap_or_revert();
+
+    storage::disable_contract_version(package_hash, previous_contract_hash).unwrap_or_revert();
+
+    runtime::put_key(BRIDGE_POOL_CONTRACT_HASH, stored_contract_hash.into());
+    let version_uref = storage::new_uref(contract_version);
+    runtime::put_key(CONTRACT_VERSION_KEY, version_uref.into());
+    runtime::put_key(CONTRACT_KEY, stored_contract_hash.into());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let chain_name = runtime::get_named_arg::<String>(CHAIN_NAME);
+    let bridge_pool_named_keys = NamedKeys::new();
+
+    // Create entry points for this contract
+    let mut bridge_pool_entry_points = EntryPoints::new();
+
+    let admin_group = Group::new(ADMIN_GROUP);
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_CONSTRUCTOR,
+        vec![
+            Parameter::new(BRIDGE_POOL_CONTRACT_PACKAGE_HASH, Key::cl_type()),
+            Parameter::new(CHAIN_NAME, String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new(CONSTRUCTOR_GROUP)]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_LIQUIDITY,
+        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_LIQUIDITY_OF,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(OWNER, Key::cl_type()),
+        ],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ADD_LIQUIDITY,
+        vec![
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(BRIDGE_POOL_CONTRACT_PACKAGE_HASH, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_REMOVE_LIQUIDITY,
+        vec![
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SWAP,
+        vec![
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(TARGET_NETWORK, U256::cl_type()),
+            Parameter::new(TARGET_TOKEN, String::cl_type()),
+            Parameter::new(TARGET_ADDRESS, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ADD_LIQUIDITY_WITH_PERMIT,
+        vec![
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(DEADLINE, u64::cl_type()),
+            Parameter::new(SIGNATURE, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SWAP_WITH_PERMIT,
+        vec![
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(TARGET_NETWORK, U256::cl_type()),
+            Parameter::new(TARGET_TOKEN, String::cl_type()),
+            Parameter::new(TARGET_ADDRESS, String::cl_type()),
+            Parameter::new(DEADLINE, u64::cl_type()),
             Parameter::new(SIGNATURE, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ADD_LIQUIDITY_NATIVE,
+        vec![
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(PURSE, URef::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SWAP_NATIVE,
+        vec![
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(TARGET_NETWORK, U256::cl_type()),
+            Parameter::new(TARGET_TOKEN, String::cl_type()),
+            Parameter::new(TARGET_ADDRESS, String::cl_type()),
+            Parameter::new(PURSE, URef::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ALLOW_TARGET,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(TOKEN_NAME, String::cl_type()),
+            Parameter::new(TARGET_NETWORK, U256::cl_type()),
+            Parameter::new(TARGET_TOKEN, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_DISALLOW_TARGET,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(TARGET_NETWORK, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_UPDATE_TARGET,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(TARGET_NETWORK, U256::cl_type()),
+            Parameter::new(TARGET_TOKEN, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_WITHDRAW_SIGNED,
+        vec![
+            Parameter::new(PAYEE, String::cl_type()),
+            Parameter::new(CHAIN_ID, u64::cl_type()),
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(SALT, String::cl_type()),
+            Parameter::new(SIGNATURES, Vec::<String>::cl_type()),
             Parameter::new(TOKEN_RECIPIENT, String::cl_type()),
             Parameter::new(CALLER, String::cl_type()),
+            Parameter::new(EXPIRY, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_WITHDRAW_SIGNED_NATIVE,
+        vec![
+            Parameter::new(PAYEE, String::cl_type()),
+            Parameter::new(CHAIN_ID, u64::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(SALT, String::cl_type()),
+            Parameter::new(SIGNATURES, Vec::<String>::cl_type()),
+            Parameter::new(TOKEN_RECIPIENT, String::cl_type()),
+            Parameter::new(CALLER, String::cl_type()),
+            Parameter::new(EXPIRY, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_WITHDRAW_SIGNED_RELAYED,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(PAYEE, String::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(RELAYER_FEE, U256::cl_type()),
+            Parameter::new(CHAIN_ID, u64::cl_type()),
+            Parameter::new(SALT, String::cl_type()),
+            Parameter::new(SIGNATURES, Vec::<String>::cl_type()),
+            Parameter::new(EXPIRY, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_WITHDRAW_SIGNED_BATCH,
+        vec![
+            Parameter::new(WITHDRAWALS, Vec::<SignedWithdrawalArgs>::cl_type()),
+            Parameter::new(REVERT_ON_FAILURE, bool::cl_type()),
+        ],
+        Vec::<u16>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ADD_SIGNER,
+        vec![Parameter::new(SIGNER, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_REMOVE_SIGNER,
+        vec![Parameter::new(SIGNER, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_CHECK_SIGNER,
+        vec![Parameter::new(SIGNER, String::cl_type())],
+        CLType::Bool,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_THRESHOLD,
+        vec![Parameter::new(THRESHOLD, u32::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_FEE,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(FEE_BPS, u32::cl_type()),
         ],
         CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_TARGET_NETWORK_FEE,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(TARGET_NETWORK, U256::cl_type()),
+            Parameter::new(FEE_BPS, u32::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_FEE_RECIPIENT,
+        vec![Parameter::new(FEE_RECIPIENT, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_WITHDRAW_FEES,
+        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_SWAP_LIMITS,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(TARGET_NETWORK, U256::cl_type()),
+            Parameter::new(MIN_AMOUNT, U256::cl_type()),
+            Parameter::new(MAX_AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_RATE_LIMIT,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(LIMIT, U256::cl_type()),
+            Parameter::new(WINDOW, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_REMOVE_RATE_LIMIT,
+        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_WITHDRAWAL_DELAY,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(THRESHOLD, U256::cl_type()),
+            Parameter::new(DELAY, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_CLAIM_WITHDRAWAL,
+        vec![Parameter::new(MESSAGE_HASH, String::cl_type())],
+        CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
 
     bridge_pool_entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_ADD_SIGNER,
-        vec![Parameter::new(SIGNER, String::cl_type())],
+        ENTRY_POINT_CANCEL_WITHDRAWAL,
+        vec![Parameter::new(MESSAGE_HASH, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_VETO_WITHDRAWAL,
+        vec![Parameter::new(MESSAGE_HASH, String::cl_type())],
         CLType::Unit,
         EntryPointAccess::Groups(vec![admin_group.clone()]),
         EntryPointType::Contract,
     ));
 
     bridge_pool_entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_REMOVE_SIGNER,
-        vec![Parameter::new(SIGNER, String::cl_type())],
+        ENTRY_POINT_SET_LP_TOKEN,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(LP_TOKEN_ADDRESS, String::cl_type()),
+        ],
         CLType::Unit,
         EntryPointAccess::Groups(vec![admin_group.clone()]),
         EntryPointType::Contract,
     ));
 
     bridge_pool_entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_CHECK_SIGNER,
-        vec![Parameter::new(SIGNER, String::cl_type())],
-        CLType::Bool,
+        ENTRY_POINT_SET_REWARD_SHARE,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(REWARD_SHARE_BPS, u32::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_TOKEN_STANDARD,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(TOKEN_STANDARD, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_BALANCE_DELTA,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(BALANCE_DELTA, bool::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_PAUSE,
+        vec![Parameter::new(OPERATION, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_UNPAUSE,
+        vec![Parameter::new(OPERATION, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_DISABLE_TOKEN,
+        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ENABLE_TOKEN,
+        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
+        CLType::Unit,
         EntryPointAccess::Groups(vec![admin_group]),
         EntryPointType::Contract,
     ));
 
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_TOKEN_STATUS,
+        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_ALLOWED_TARGET,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(TARGET_NETWORK, U256::cl_type()),
+        ],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_TOKEN_NAME,
+        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_TOKEN_STANDARD,
+        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_BALANCE_DELTA,
+        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_MIGRATE_STORAGE,
+        vec![
+            Parameter::new(TOKEN_ADDRESSES, Vec::<String>::cl_type()),
+            Parameter::new(TARGET_NETWORKS, Vec::<U256>::cl_type()),
+            Parameter::new(PROVIDERS, Vec::<Key>::cl_type()),
+            Parameter::new(SIGNERS, Vec::<String>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_UPGRADE,
+        vec![
+            Parameter::new(CHAIN_NAME, String::cl_type()),
+            Parameter::new(TOKEN_ADDRESSES, Vec::<String>::cl_type()),
+            Parameter::new(TARGET_NETWORKS, Vec::<U256>::cl_type()),
+            Parameter::new(PROVIDERS, Vec::<Key>::cl_type()),
+            Parameter::new(SIGNERS, Vec::<String>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![Group::new(CONSTRUCTOR_GROUP)]),
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_SWAP_LIMITS,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(TARGET_NETWORK, U256::cl_type()),
+        ],
+        <(U256, U256)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_LP_TOKEN,
+        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_CLAIM_REWARDS,
+        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_TRANSFER_REWARD_SHARES,
+        vec![
+            Parameter::new(OWNER, Key::cl_type()),
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_PENDING_REWARDS,
+        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_TOTAL_LIQUIDITY,
+        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_POOL_BALANCE,
+        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_LIST_TARGET_NETWORKS,
+        vec![Parameter::new(TOKEN_ADDRESS, String::cl_type())],
+        Vec::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_LIST_SIGNERS,
+        vec![
+            Parameter::new(OFFSET, u32::cl_type()),
+            Parameter::new(LIMIT, u32::cl_type()),
+        ],
+        Vec::<String>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_LIST_TOKENS,
+        vec![
+            Parameter::new(OFFSET, u32::cl_type()),
+            Parameter::new(LIMIT, u32::cl_type()),
+        ],
+        Vec::<String>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    bridge_pool_entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_LIST_LIQUIDITY_PROVIDERS,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(OFFSET, u32::cl_type()),
+            Parameter::new(LIMIT, u32::cl_type()),
+        ],
+        Vec::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    // An installed pool gets a new version of its package instead
+    if let Some(package_key) = runtime::get_key(BRIDGE_POOL_PACKAGE_NAME) {
+        let package_hash = ContractPackageHash::new(package_key.into_hash().unwrap_or_revert());
+        upgrade_installed_pool(package_hash, bridge_pool_entry_points, chain_name);
+        return;
+    }
+
     // Create a new contract package that can be upgraded
     let (stored_contract_hash, contract_version) = storage::new_contract(
         bridge_pool_entry_points,
@@ -329,6 +1477,7 @@ pub extern "C" fn call() {
         ENTRY_POINT_CONSTRUCTOR,
         runtime_args! {
             BRIDGE_POOL_CONTRACT_PACKAGE_HASH => package_hash_key,
+            CHAIN_NAME => chain_name,
         },
     );
 
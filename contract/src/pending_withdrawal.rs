@@ -0,0 +1,71 @@
+//! Implementation of a `PendingWithdrawal`, a large withdrawal waiting for its time lock to pass.
+use alloc::vec::Vec;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, ContractPackageHash, U256,
+};
+
+use crate::address::Address;
+
+/// A withdrawal authorized by `withdraw_signed` or `withdraw_signed_relayed` that can only be
+/// claimed after `release_time`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct PendingWithdrawal {
+    /// The token to be paid out.
+    pub token: ContractPackageHash,
+    /// The address the withdrawal is paid to, and the only one allowed to claim it unless it was
+    /// relayed.
+    pub recipient: Address,
+    /// The amount to be paid out.
+    pub amount: U256,
+    /// The block time in milliseconds from which the withdrawal can be claimed.
+    pub release_time: u64,
+    /// The fee paid to whoever claims a relayed withdrawal, `None` if it was not relayed.
+    pub relayer_fee: Option<U256>,
+}
+
+impl CLTyped for PendingWithdrawal {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for PendingWithdrawal {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.token.to_bytes()?);
+        result.append(&mut self.recipient.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.release_time.to_bytes()?);
+        result.append(&mut self.relayer_fee.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.token.serialized_length()
+            + self.recipient.serialized_length()
+            + self.amount.serialized_length()
+            + self.release_time.serialized_length()
+            + self.relayer_fee.serialized_length()
+    }
+}
+
+impl FromBytes for PendingWithdrawal {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (token, remainder) = ContractPackageHash::from_bytes(bytes)?;
+        let (recipient, remainder) = Address::from_bytes(remainder)?;
+        let (amount, remainder) = U256::from_bytes(remainder)?;
+        let (release_time, remainder) = u64::from_bytes(remainder)?;
+        let (relayer_fee, remainder) = Option::<U256>::from_bytes(remainder)?;
+
+        let pending_withdrawal = PendingWithdrawal {
+            token,
+            recipient,
+            amount,
+            release_time,
+            relayer_fee,
+        };
+
+        Ok((pending_withdrawal, remainder))
+    }
+}
@@ -0,0 +1,48 @@
+//! Implementation of a `SignedWithdrawal`, one item of a `withdraw_signed_batch`.
+use alloc::{string::String, vec::Vec};
+use casper_types::U256;
+
+/// A withdrawal as passed to `withdraw_signed_batch`, grouped as
+/// `((token_address, payee, amount), (chain_id, salt, caller), (signatures, token_recipient,
+/// expiry))` since CL tuples hold at most three values.
+pub type SignedWithdrawalArgs = (
+    (String, String, U256),
+    (u64, String, String),
+    (Vec<String>, String, u64),
+);
+
+/// A withdrawal authorized by the signatures of the signers, with the arguments of
+/// `withdraw_signed`.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct SignedWithdrawal {
+    pub token_address: String,
+    pub payee: String,
+    pub amount: U256,
+    pub chain_id: u64,
+    pub salt: String,
+    pub caller: String,
+    pub signatures: Vec<String>,
+    pub token_recipient: String,
+    pub expiry: u64,
+}
+
+impl From<SignedWithdrawalArgs> for SignedWithdrawal {
+    fn from(args: SignedWithdrawalArgs) -> Self {
+        let (
+            (token_address, payee, amount),
+            (chain_id, salt, caller),
+            (signatures, token_recipient, expiry),
+        ) = args;
+        SignedWithdrawal {
+            token_address,
+            payee,
+            amount,
+            chain_id,
+            salt,
+            caller,
+            signatures,
+            token_recipient,
+            expiry,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Implementation of a `TokenStandard`, the interface the bridge pool uses to call a token.
+use alloc::vec::Vec;
+use casper_types::{
+    bytesrepr::{self, FromBytes},
+    CLType, CLTyped,
+};
+
+use crate::consts::{TOKEN_STANDARD_CEP18, TOKEN_STANDARD_ERC20};
+use crate::error::Error;
+
+/// The token interfaces the bridge pool can transfer tokens through.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum TokenStandard {
+    /// The layout of the in-repo `erc20` package, whose transfer entry points return nothing.
+    Erc20,
+    /// A standard CEP-18 token, which may return values from its transfer entry points and emit
+    /// CES events.
+    Cep18,
+}
+
+impl TokenStandard {
+    /// Returns the name the standard is selected with.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TokenStandard::Erc20 => TOKEN_STANDARD_ERC20,
+            TokenStandard::Cep18 => TOKEN_STANDARD_CEP18,
+        }
+    }
+
+    /// Returns the standard selected with `name`.
+    pub fn from_name(name: &str) -> Result<TokenStandard, Error> {
+        match name {
+            TOKEN_STANDARD_ERC20 => Ok(TokenStandard::Erc20),
+            TOKEN_STANDARD_CEP18 => Ok(TokenStandard::Cep18),
+            _ => Err(Error::UnknownTokenStandard),
+        }
+    }
+}
+
+/// The return value of a token call that is not used, whatever its type.
+pub(crate) struct IgnoredReturnValue;
+
+impl CLTyped for IgnoredReturnValue {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for IgnoredReturnValue {
+    fn from_bytes(_bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        Ok((IgnoredReturnValue, &[]))
+    }
+
+    fn from_vec(_bytes: Vec<u8>) -> Result<(Self, Vec<u8>), bytesrepr::Error> {
+        Ok((IgnoredReturnValue, Vec::new()))
+    }
+}
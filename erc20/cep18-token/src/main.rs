@@ -0,0 +1,227 @@
+//! CEP-18 token used to test the bridge pool against a token that does not follow the layout of
+//! `erc20-token`. Its transfer entry points return a success flag, and every transfer is recorded
+//! as a CES style event in the `__events` dictionary.
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc20::{
+    constants::{
+        ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, CHAIN_NAME_RUNTIME_ARG_NAME,
+        DECIMALS_RUNTIME_ARG_NAME, NAME_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME,
+        RECIPIENT_RUNTIME_ARG_NAME, SPENDER_RUNTIME_ARG_NAME, SYMBOL_RUNTIME_ARG_NAME,
+        TOTAL_SUPPLY_RUNTIME_ARG_NAME, TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
+    },
+    entry_points, Address, Error, ERC20,
+};
+use casper_types::{
+    bytesrepr::ToBytes, contracts::NamedKeys, system::CallStackElement, CLTyped, CLValue,
+    EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter, URef, U256,
+};
+
+const CEP18_TOKEN_CONTRACT_KEY_NAME: &str = "cep18_token_contract";
+const CEP18_TOKEN_CONTRACT_PACKAGE_KEY_NAME: &str = "cep18_token_contract_package_hash";
+const EVENTS_KEY_NAME: &str = "__events";
+const EVENTS_LENGTH_KEY_NAME: &str = "__events_length";
+const TRANSFER_EVENT_NAME: &str = "event_Transfer";
+const TRANSFER_FROM_EVENT_NAME: &str = "event_TransferFrom";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let name: String = runtime::get_named_arg(NAME_RUNTIME_ARG_NAME);
+    let symbol: String = runtime::get_named_arg(SYMBOL_RUNTIME_ARG_NAME);
+    let decimals: u8 = runtime::get_named_arg(DECIMALS_RUNTIME_ARG_NAME);
+    let total_supply: U256 = runtime::get_named_arg(TOTAL_SUPPLY_RUNTIME_ARG_NAME);
+    let chain_name: String = runtime::get_named_arg(CHAIN_NAME_RUNTIME_ARG_NAME);
+
+    let events_uref = storage::new_dictionary(EVENTS_KEY_NAME).unwrap_or_revert();
+    runtime::remove_key(EVENTS_KEY_NAME);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(String::from(EVENTS_KEY_NAME), events_uref.into());
+    named_keys.insert(
+        String::from(EVENTS_LENGTH_KEY_NAME),
+        storage::new_uref(0u32).into(),
+    );
+
+    let _token = ERC20::install_with_named_keys(
+        name,
+        symbol,
+        decimals,
+        total_supply,
+        chain_name,
+        CEP18_TOKEN_CONTRACT_KEY_NAME,
+        CEP18_TOKEN_CONTRACT_PACKAGE_KEY_NAME,
+        cep18_token_entry_points(),
+        named_keys,
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn name() {
+    let name = ERC20::default().name();
+    runtime::ret(CLValue::from_t(name).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn symbol() {
+    let symbol = ERC20::default().symbol();
+    runtime::ret(CLValue::from_t(symbol).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn decimals() {
+    let decimals = ERC20::default().decimals();
+    runtime::ret(CLValue::from_t(decimals).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    let total_supply = ERC20::default().total_supply();
+    runtime::ret(CLValue::from_t(total_supply).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let balance = ERC20::default().balance_of(address);
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC20::default()
+        .transfer(recipient, amount)
+        .unwrap_or_revert();
+
+    let sender = get_immediate_caller_address().unwrap_or_revert();
+    record_event(TRANSFER_EVENT_NAME, sender, recipient, amount);
+    runtime::ret(CLValue::from_t(true).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC20::default()
+        .transfer_from(owner, recipient, amount)
+        .unwrap_or_revert();
+
+    record_event(TRANSFER_FROM_EVENT_NAME, owner, recipient, amount);
+    runtime::ret(CLValue::from_t(true).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC20::default().approve(spender, amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn allowance() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount = ERC20::default().allowance(owner, spender);
+    runtime::ret(CLValue::from_t(amount).unwrap_or_revert());
+}
+
+/// Appends an event, serialized as its name followed by its fields, to the `__events` dictionary.
+fn record_event(event_name: &str, owner: Address, recipient: Address, amount: U256) {
+    let length_uref = get_uref(EVENTS_LENGTH_KEY_NAME);
+    let length: u32 = storage::read(length_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+
+    let mut event: Vec<u8> = event_name.to_bytes().unwrap_or_revert();
+    event.append(&mut owner.to_bytes().unwrap_or_revert());
+    event.append(&mut recipient.to_bytes().unwrap_or_revert());
+    event.append(&mut amount.to_bytes().unwrap_or_revert());
+
+    storage::dictionary_put(get_uref(EVENTS_KEY_NAME), &length.to_string(), event);
+    storage::write(length_uref, length + 1);
+}
+
+fn get_uref(name: &str) -> URef {
+    runtime::get_key(name)
+        .and_then(Key::into_uref)
+        .unwrap_or_revert()
+}
+
+/// Gets the immediate call stack element of the current execution.
+fn get_immediate_call_stack_item() -> Option<CallStackElement> {
+    let call_stack = runtime::get_call_stack();
+    call_stack.into_iter().rev().nth(1)
+}
+
+fn call_stack_element_to_address(call_stack_element: CallStackElement) -> Address {
+    match call_stack_element {
+        CallStackElement::Session { account_hash } => Address::from(account_hash),
+        CallStackElement::StoredSession { account_hash, .. } => Address::from(account_hash),
+        CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        } => Address::from(contract_package_hash),
+    }
+}
+
+fn get_immediate_caller_address() -> Result<Address, Error> {
+    get_immediate_call_stack_item()
+        .map(call_stack_element_to_address)
+        .ok_or(Error::InvalidContext)
+}
+
+/// Returns the `transfer` or `transfer_from` entry point, both returning a success flag.
+fn transfer_entry_point(name: &str, mut params: Vec<Parameter>) -> EntryPoint {
+    params.push(Parameter::new(
+        RECIPIENT_RUNTIME_ARG_NAME,
+        Address::cl_type(),
+    ));
+    params.push(Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()));
+    EntryPoint::new(
+        String::from(name),
+        params,
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the ERC20 entry points without the faucet `mint`, with transfers returning a flag.
+fn cep18_token_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(entry_points::name());
+    entry_points.add_entry_point(entry_points::symbol());
+    entry_points.add_entry_point(entry_points::decimals());
+    entry_points.add_entry_point(entry_points::total_supply());
+    entry_points.add_entry_point(entry_points::balance_of());
+    entry_points.add_entry_point(transfer_entry_point(TRANSFER_ENTRY_POINT_NAME, vec![]));
+    entry_points.add_entry_point(entry_points::approve());
+    entry_points.add_entry_point(entry_points::allowance());
+    entry_points.add_entry_point(transfer_entry_point(
+        TRANSFER_FROM_ENTRY_POINT_NAME,
+        vec![Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type())],
+    ));
+    entry_points
+}
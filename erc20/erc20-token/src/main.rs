@@ -14,8 +14,9 @@ use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
 
 use casper_erc20::{
     constants::{
-        ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, DECIMALS_RUNTIME_ARG_NAME,
-        NAME_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
+        ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, CHAIN_NAME_RUNTIME_ARG_NAME,
+        DEADLINE_RUNTIME_ARG_NAME, DECIMALS_RUNTIME_ARG_NAME, NAME_RUNTIME_ARG_NAME,
+        OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME, SIGNATURE_RUNTIME_ARG_NAME,
         SPENDER_RUNTIME_ARG_NAME, SYMBOL_RUNTIME_ARG_NAME, TOTAL_SUPPLY_RUNTIME_ARG_NAME,
     },
     Address, ERC20, Error,
@@ -30,6 +31,7 @@ pub extern "C" fn call() {
     let symbol: String = runtime::get_named_arg(SYMBOL_RUNTIME_ARG_NAME);
     let total_supply = runtime::get_named_arg(TOTAL_SUPPLY_RUNTIME_ARG_NAME);
     let decimals = runtime::get_named_arg(DECIMALS_RUNTIME_ARG_NAME);
+    let chain_name: String = runtime::get_named_arg(CHAIN_NAME_RUNTIME_ARG_NAME);
     // let total_supply = U256::from(totalsupply);
         // let decimal: u8 = 8;
     // let decimals = U256::from(decimal);
@@ -38,7 +40,8 @@ pub extern "C" fn call() {
     // let total_supply: u8 = 1_00;
     // let decimal: u8 = 8;
     // let decimals = U256::from(decimal);
-    let _token = ERC20::install(name, symbol, decimals, total_supply).unwrap_or_revert();
+    let _token =
+        ERC20::install(name, symbol, decimals, total_supply, chain_name).unwrap_or_revert();
 }
 
 #[no_mangle]
@@ -105,6 +108,24 @@ pub extern "C" fn allowance(){
     runtime::ret(CLValue::from_t(amount).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub extern "C" fn permit(){
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let deadline: u64 = runtime::get_named_arg(DEADLINE_RUNTIME_ARG_NAME);
+    let signature: String = runtime::get_named_arg(SIGNATURE_RUNTIME_ARG_NAME);
+
+    ERC20::default().permit(owner, spender, amount, deadline, signature).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn nonces(){
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let nonce = ERC20::default().nonces(owner);
+    runtime::ret(CLValue::from_t(nonce).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn burn(){
     let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
@@ -8,12 +8,20 @@ pub const SYMBOL_KEY_NAME: &str = "symbol";
 pub const DECIMALS_KEY_NAME: &str = "decimals";
 /// Name of named-key for `contract`
 pub const ERC20_TOKEN_CONTRACT_KEY_NAME: &str = "erc20_token_contract";
+/// Name of named-key for the contract package
+pub const ERC20_TOKEN_CONTRACT_PACKAGE_KEY_NAME: &str = "erc20-contract_package_hash";
 /// Name of dictionary-key for `balances`
 pub const BALANCES_KEY_NAME: &str = "balances";
 /// Name of dictionary-key for `allowances`
 pub const ALLOWANCES_KEY_NAME: &str = "allowances";
 /// Name of named-key for `total_supply`
 pub const TOTAL_SUPPLY_KEY_NAME: &str = "total_supply";
+/// Name of dictionary-key for `nonces`
+pub const NONCES_KEY_NAME: &str = "nonces";
+/// Name of named-key for the name of the Casper network permits are signed for.
+pub const CHAIN_NAME_KEY_NAME: &str = "chain_name";
+/// Version of the EIP-712 domain permits are signed for.
+pub const PERMIT_VERSION: &str = "1";
 
 /// Name of `name` entry point.
 pub const NAME_ENTRY_POINT_NAME: &str = "name";
@@ -33,6 +41,10 @@ pub const ALLOWANCE_ENTRY_POINT_NAME: &str = "allowance";
 pub const TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
 /// Name of `total_supply` entry point.
 pub const TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "total_supply";
+/// Name of `permit` entry point.
+pub const PERMIT_ENTRY_POINT_NAME: &str = "permit";
+/// Name of `nonces` entry point.
+pub const NONCES_ENTRY_POINT_NAME: &str = "nonces";
 
 /// Name of `address` runtime argument.
 pub const ADDRESS_RUNTIME_ARG_NAME: &str = "address";
@@ -52,3 +64,9 @@ pub const SYMBOL_RUNTIME_ARG_NAME: &str = "symbol";
 pub const DECIMALS_RUNTIME_ARG_NAME: &str = "decimals";
 /// Name of `total_supply` runtime argument.
 pub const TOTAL_SUPPLY_RUNTIME_ARG_NAME: &str = "total_supply";
+/// Name of `chain_name` runtime argument.
+pub const CHAIN_NAME_RUNTIME_ARG_NAME: &str = "chain_name";
+/// Name of `deadline` runtime argument.
+pub const DEADLINE_RUNTIME_ARG_NAME: &str = "deadline";
+/// Name of `signature` runtime argument.
+pub const SIGNATURE_RUNTIME_ARG_NAME: &str = "signature";
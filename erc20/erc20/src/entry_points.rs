@@ -9,10 +9,11 @@ use crate::{
     address::Address,
     constants::{
         ADDRESS_RUNTIME_ARG_NAME, ALLOWANCE_ENTRY_POINT_NAME, AMOUNT_RUNTIME_ARG_NAME,
-        APPROVE_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME, DECIMALS_ENTRY_POINT_NAME,
-        NAME_ENTRY_POINT_NAME, OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
-        SPENDER_RUNTIME_ARG_NAME, SYMBOL_ENTRY_POINT_NAME, TOTAL_SUPPLY_ENTRY_POINT_NAME,
-        TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
+        APPROVE_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME, DEADLINE_RUNTIME_ARG_NAME,
+        DECIMALS_ENTRY_POINT_NAME, NAME_ENTRY_POINT_NAME, NONCES_ENTRY_POINT_NAME,
+        OWNER_RUNTIME_ARG_NAME, PERMIT_ENTRY_POINT_NAME, RECIPIENT_RUNTIME_ARG_NAME,
+        SIGNATURE_RUNTIME_ARG_NAME, SPENDER_RUNTIME_ARG_NAME, SYMBOL_ENTRY_POINT_NAME,
+        TOTAL_SUPPLY_ENTRY_POINT_NAME, TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
     },
 };
 
@@ -95,6 +96,34 @@ pub fn approve() -> EntryPoint {
     )
 }
 
+/// Returns the `permit` entry point.
+pub fn permit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PERMIT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DEADLINE_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(SIGNATURE_RUNTIME_ARG_NAME, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `nonces` entry point.
+pub fn nonces() -> EntryPoint {
+    EntryPoint::new(
+        String::from(NONCES_ENTRY_POINT_NAME),
+        vec![Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `transfer` entry point.
 pub fn transfer() -> EntryPoint {
     EntryPoint::new(
@@ -165,6 +194,8 @@ pub fn default() -> EntryPoints {
     entry_points.add_entry_point(approve());
     entry_points.add_entry_point(allowance());
     entry_points.add_entry_point(transfer_from());
+    entry_points.add_entry_point(permit());
+    entry_points.add_entry_point(nonces());
     entry_points.add_entry_point(mint());
     entry_points
 }
@@ -9,7 +9,7 @@ use casper_types::ApiError;
 /// return those via the [`Error::User`] variant or equivalently via the [`ApiError::User`]
 /// variant.
 ///
-/// Such a user error should be in the range `[0..(u16::MAX - 4)]` (i.e. [0, 65532]) to avoid
+/// Such a user error should be in the range `[0..(u16::MAX - 6)]` (i.e. [0, 65530]) to avoid
 /// conflicting with the other `Error` variants.
 #[derive(Debug)]
 pub enum Error {
@@ -21,6 +21,10 @@ pub enum Error {
     InsufficientAllowance,
     /// Operation would cause an integer overflow.
     Overflow,
+    /// Permit was submitted after its deadline.
+    PermitExpired,
+    /// Permit is not signed by the owner for this token, spender, amount and nonce.
+    InvalidPermitSignature,
     /// User error.
     User(u16),
     /// User allowance error.
@@ -31,6 +35,8 @@ const ERROR_INVALID_CONTEXT: u16 = u16::MAX;
 const ERROR_INSUFFICIENT_BALANCE: u16 = u16::MAX - 1;
 const ERROR_INSUFFICIENT_ALLOWANCE: u16 = u16::MAX - 2;
 const ERROR_OVERFLOW: u16 = u16::MAX - 3;
+const ERROR_PERMIT_EXPIRED: u16 = u16::MAX - 4;
+const ERROR_INVALID_PERMIT_SIGNATURE: u16 = u16::MAX - 5;
 
 impl From<Error> for ApiError {
     fn from(error: Error) -> Self {
@@ -39,6 +45,8 @@ impl From<Error> for ApiError {
             Error::InsufficientBalance => ERROR_INSUFFICIENT_BALANCE,
             Error::InsufficientAllowance => ERROR_INSUFFICIENT_ALLOWANCE,
             Error::Overflow => ERROR_OVERFLOW,
+            Error::PermitExpired => ERROR_PERMIT_EXPIRED,
+            Error::InvalidPermitSignature => ERROR_INVALID_PERMIT_SIGNATURE,
             Error::User(user_error) => user_error,
             Error::UserAllowance(user_allowance) => user_allowance,
         };
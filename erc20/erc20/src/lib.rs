@@ -22,6 +22,7 @@ pub mod constants;
 mod detail;
 pub mod entry_points;
 mod error;
+mod permits;
 mod total_supply;
 
 use alloc::string::{String, ToString};
@@ -36,8 +37,9 @@ use casper_types::{contracts::NamedKeys, EntryPoints, Key, URef, U256};
 
 pub use address::Address;
 use constants::{
-    ALLOWANCES_KEY_NAME, BALANCES_KEY_NAME, DECIMALS_KEY_NAME, ERC20_TOKEN_CONTRACT_KEY_NAME,
-    NAME_KEY_NAME, SYMBOL_KEY_NAME, TOTAL_SUPPLY_KEY_NAME,
+    ALLOWANCES_KEY_NAME, BALANCES_KEY_NAME, CHAIN_NAME_KEY_NAME, DECIMALS_KEY_NAME,
+    ERC20_TOKEN_CONTRACT_KEY_NAME, ERC20_TOKEN_CONTRACT_PACKAGE_KEY_NAME, NAME_KEY_NAME,
+    NONCES_KEY_NAME, SYMBOL_KEY_NAME, TOTAL_SUPPLY_KEY_NAME,
 };
 pub use error::Error;
 
@@ -47,14 +49,21 @@ pub struct ERC20 {
     balances_uref: OnceCell<URef>,
     allowances_uref: OnceCell<URef>,
     total_supply_uref: OnceCell<URef>,
+    nonces_uref: OnceCell<URef>,
 }
 
 impl ERC20 {
-    fn new(balances_uref: URef, allowances_uref: URef, total_supply_uref: URef) -> Self {
+    fn new(
+        balances_uref: URef,
+        allowances_uref: URef,
+        total_supply_uref: URef,
+        nonces_uref: URef,
+    ) -> Self {
         Self {
             balances_uref: balances_uref.into(),
             allowances_uref: allowances_uref.into(),
             total_supply_uref: total_supply_uref.into(),
+            nonces_uref: nonces_uref.into(),
         }
     }
 
@@ -101,6 +110,18 @@ impl ERC20 {
         allowances::write_allowance_to(allowance_uref, owner, spender, amount)
     }
 
+    fn nonces_uref(&self) -> URef {
+        *self.nonces_uref.get_or_init(permits::nonces_uref)
+    }
+
+    fn read_nonce(&self, owner: Address) -> U256 {
+        permits::read_nonce_from(self.nonces_uref(), owner)
+    }
+
+    fn write_nonce(&mut self, owner: Address, nonce: U256) {
+        permits::write_nonce_to(self.nonces_uref(), owner, nonce)
+    }
+
     fn transfer_balance(
         &mut self,
         sender: Address,
@@ -112,12 +133,14 @@ impl ERC20 {
 
     /// Installs the ERC20 contract with the default set of entry points.
     ///
-    /// This should be called from within `fn call()` of your contract.
+    /// This should be called from within `fn call()` of your contract. `chain_name` is the name of
+    /// the Casper network the contract is installed on, which permits are signed for.
     pub fn install(
         name: String,
         symbol: String,
         decimals: u8,
         initial_supply: U256,
+        chain_name: String,
     ) -> Result<ERC20, Error> {
         let default_entry_points = entry_points::default();
         ERC20::install_custom(
@@ -125,6 +148,7 @@ impl ERC20 {
             symbol,
             decimals,
             initial_supply,
+            chain_name,
             ERC20_TOKEN_CONTRACT_KEY_NAME,
             default_entry_points,
         )
@@ -145,6 +169,11 @@ impl ERC20 {
         detail::read_from(DECIMALS_KEY_NAME)
     }
 
+    /// Returns the name of the Casper network the token is installed on.
+    pub fn chain_name(&self) -> String {
+        detail::read_from(CHAIN_NAME_KEY_NAME)
+    }
+
     /// Returns the total supply of the token.
     pub fn total_supply(&self) -> U256 {
         self.read_total_supply()
@@ -194,6 +223,50 @@ impl ERC20 {
         self.read_allowance(owner, spender)
     }
 
+    /// Allows `spender` to transfer up to `amount` of `owner`'s tokens, approved by the owner's
+    /// secp256k1 signature of the permit instead of a deploy of the owner.
+    ///
+    /// The permit must be submitted at or before the `deadline` block time and be signed for the
+    /// owner's current nonce, which is then incremented so that the permit cannot be replayed.
+    pub fn permit(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        amount: U256,
+        deadline: u64,
+        signature: String,
+    ) -> Result<(), Error> {
+        if u64::from(runtime::get_blocktime()) > deadline {
+            return Err(Error::PermitExpired);
+        }
+        let token = *detail::get_caller_address()?
+            .as_contract_package_hash()
+            .ok_or(Error::InvalidContext)?;
+
+        let nonce = self.read_nonce(owner);
+        permits::verify_permit_signature(
+            token,
+            &self.name(),
+            &self.chain_name(),
+            owner,
+            spender,
+            amount,
+            nonce,
+            deadline,
+            &signature,
+        )?;
+
+        let new_nonce = nonce.checked_add(U256::one()).ok_or(Error::Overflow)?;
+        self.write_nonce(owner, new_nonce);
+        self.write_allowance(owner, spender, amount);
+        Ok(())
+    }
+
+    /// Returns the nonce the next permit of `owner` has to be signed with.
+    pub fn nonces(&self, owner: Address) -> U256 {
+        self.read_nonce(owner)
+    }
+
     /// Mints `amount` new tokens and adds them to `owner`'s balance and to the token total supply.
     ///
     /// # Security
@@ -250,16 +323,46 @@ impl ERC20 {
         symbol: String,
         decimals: u8,
         initial_supply: U256,
+        chain_name: String,
+        contract_key_name: &str,
+        entry_points: EntryPoints,
+    ) -> Result<ERC20, Error> {
+        ERC20::install_with_named_keys(
+            name,
+            symbol,
+            decimals,
+            initial_supply,
+            chain_name,
+            contract_key_name,
+            ERC20_TOKEN_CONTRACT_PACKAGE_KEY_NAME,
+            entry_points,
+            NamedKeys::new(),
+        )
+    }
+
+    /// Installs the ERC20 contract with a custom set of entry points and additional named keys.
+    ///
+    /// The hash of the contract package is stored in the caller's named keys under
+    /// `package_key_name`, so that several tokens can be installed by the same account.
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn install_with_named_keys(
+        name: String,
+        symbol: String,
+        decimals: u8,
+        initial_supply: U256,
+        chain_name: String,
         contract_key_name: &str,
+        package_key_name: &str,
         entry_points: EntryPoints,
+        mut named_keys: NamedKeys,
     ) -> Result<ERC20, Error> {
         let balances_uref = storage::new_dictionary(BALANCES_KEY_NAME).unwrap_or_revert();
         let allowances_uref = storage::new_dictionary(ALLOWANCES_KEY_NAME).unwrap_or_revert();
+        let nonces_uref = storage::new_dictionary(NONCES_KEY_NAME).unwrap_or_revert();
         // We need to hold on a RW access rights because tokens can be minted or burned.
         let total_supply_uref = storage::new_uref(initial_supply).into_read_write();
 
-        let mut named_keys = NamedKeys::new();
-
         let name_key = {
             let name_uref = storage::new_uref(name).into_read();
             Key::from(name_uref)
@@ -275,6 +378,11 @@ impl ERC20 {
             Key::from(decimals_uref)
         };
 
+        let chain_name_key = {
+            let chain_name_uref = storage::new_uref(chain_name).into_read();
+            Key::from(chain_name_uref)
+        };
+
         let total_supply_key = Key::from(total_supply_uref);
 
         let balances_dictionary_key = {
@@ -293,15 +401,23 @@ impl ERC20 {
             Key::from(allowances_uref)
         };
 
+        let nonces_dictionary_key = {
+            runtime::remove_key(NONCES_KEY_NAME);
+
+            Key::from(nonces_uref)
+        };
+
         named_keys.insert(NAME_KEY_NAME.to_string(), name_key);
         named_keys.insert(SYMBOL_KEY_NAME.to_string(), symbol_key);
         named_keys.insert(DECIMALS_KEY_NAME.to_string(), decimals_key);
+        named_keys.insert(CHAIN_NAME_KEY_NAME.to_string(), chain_name_key);
         named_keys.insert(BALANCES_KEY_NAME.to_string(), balances_dictionary_key);
         named_keys.insert(ALLOWANCES_KEY_NAME.to_string(), allowances_dictionary_key);
         named_keys.insert(TOTAL_SUPPLY_KEY_NAME.to_string(), total_supply_key);
+        named_keys.insert(NONCES_KEY_NAME.to_string(), nonces_dictionary_key);
 
         let (contract_hash, _version) =
-            storage::new_locked_contract(entry_points, Some(named_keys), Some(String::from("erc20-contract_package_hash")), None);
+            storage::new_locked_contract(entry_points, Some(named_keys), Some(String::from(package_key_name)), None);
 
         // Hash of the installed contract will be reachable through named keys.
         runtime::put_key(contract_key_name, Key::from(contract_hash));
@@ -310,6 +426,7 @@ impl ERC20 {
             balances_uref,
             allowances_uref,
             total_supply_uref,
+            nonces_uref,
         ))
     }
 }
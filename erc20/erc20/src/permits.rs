@@ -0,0 +1,88 @@
+//! Implementation of permits, allowances approved by a signature of the owner instead of a deploy.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    account::AccountHash, bytesrepr::ToBytes, ContractPackageHash, PublicKey, URef, U256,
+};
+use contract_utils::keccak;
+
+use crate::{
+    constants::{NONCES_KEY_NAME, PERMIT_VERSION},
+    detail,
+    error::Error,
+    Address,
+};
+
+#[inline]
+pub(crate) fn nonces_uref() -> URef {
+    detail::get_uref(NONCES_KEY_NAME)
+}
+
+/// Creates a dictionary item key for an owner, in the same way as the balances do.
+#[inline]
+fn make_dictionary_item_key(owner: Address) -> String {
+    let preimage = owner.to_bytes().unwrap_or_revert();
+    base64::encode(&preimage)
+}
+
+/// Reads the nonce the next permit of `owner` has to be signed with.
+///
+/// If a given owner has never used a permit, then a 0 is returned.
+pub(crate) fn read_nonce_from(nonces_uref: URef, owner: Address) -> U256 {
+    let dictionary_item_key = make_dictionary_item_key(owner);
+
+    storage::dictionary_get(nonces_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Writes the nonce the next permit of `owner` has to be signed with.
+pub(crate) fn write_nonce_to(nonces_uref: URef, owner: Address, nonce: U256) {
+    let dictionary_item_key = make_dictionary_item_key(owner);
+    storage::dictionary_put(nonces_uref, &dictionary_item_key, nonce);
+}
+
+/// Checks that `signature` is a secp256k1 signature by the key of the `owner` account over the
+/// permit digest of this token on the Casper network it is installed on.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn verify_permit_signature(
+    token: ContractPackageHash,
+    name: &str,
+    chain_name: &str,
+    owner: Address,
+    spender: Address,
+    amount: U256,
+    nonce: U256,
+    deadline: u64,
+    signature: &str,
+) -> Result<(), Error> {
+    let owner_account = *owner
+        .as_account_hash()
+        .ok_or(Error::InvalidPermitSignature)?;
+
+    let domain_separator =
+        keccak::permit_domain_separator(token.value(), name, PERMIT_VERSION, chain_name);
+    let message_hash = keccak::permit_message_hash(
+        domain_separator,
+        &owner.to_bytes().unwrap_or_revert(),
+        &spender.to_bytes().unwrap_or_revert(),
+        amount,
+        nonce,
+        deadline,
+    );
+
+    let signature: Vec<u8> = hex::decode(signature).map_err(|_| Error::InvalidPermitSignature)?;
+    let public_key = keccak::ecdsa_recover_public_key(&message_hash, &signature)
+        .map_err(|_| Error::InvalidPermitSignature)?;
+    let public_key =
+        PublicKey::secp256k1_from_bytes(public_key).map_err(|_| Error::InvalidPermitSignature)?;
+
+    if AccountHash::from_public_key(&public_key, runtime::blake2b) != owner_account {
+        return Err(Error::InvalidPermitSignature);
+    }
+    Ok(())
+}
@@ -0,0 +1,162 @@
+//! Deflationary ERC20 token used to test the bridge pool against a token that charges a fee on
+//! every transfer. The fee, in basis points of the transferred amount, is burned from the
+//! recipient.
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc20::{
+    constants::{
+        ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, CHAIN_NAME_RUNTIME_ARG_NAME,
+        DECIMALS_RUNTIME_ARG_NAME, NAME_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME,
+        RECIPIENT_RUNTIME_ARG_NAME, SPENDER_RUNTIME_ARG_NAME, SYMBOL_RUNTIME_ARG_NAME,
+        TOTAL_SUPPLY_RUNTIME_ARG_NAME,
+    },
+    entry_points, Address, ERC20,
+};
+use casper_types::{contracts::NamedKeys, CLValue, EntryPoints, Key, U256};
+
+const TRANSFER_FEE_RUNTIME_ARG_NAME: &str = "transfer_fee";
+const TRANSFER_FEE_KEY_NAME: &str = "transfer_fee";
+const FEE_TOKEN_CONTRACT_KEY_NAME: &str = "fee_token_contract";
+const FEE_TOKEN_CONTRACT_PACKAGE_KEY_NAME: &str = "fee_token_contract_package_hash";
+const FEE_DENOMINATOR: u32 = 10_000;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let name: String = runtime::get_named_arg(NAME_RUNTIME_ARG_NAME);
+    let symbol: String = runtime::get_named_arg(SYMBOL_RUNTIME_ARG_NAME);
+    let decimals: u8 = runtime::get_named_arg(DECIMALS_RUNTIME_ARG_NAME);
+    let total_supply: U256 = runtime::get_named_arg(TOTAL_SUPPLY_RUNTIME_ARG_NAME);
+    let chain_name: String = runtime::get_named_arg(CHAIN_NAME_RUNTIME_ARG_NAME);
+    let transfer_fee: u32 = runtime::get_named_arg(TRANSFER_FEE_RUNTIME_ARG_NAME);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        String::from(TRANSFER_FEE_KEY_NAME),
+        storage::new_uref(transfer_fee).into_read().into(),
+    );
+
+    let _token = ERC20::install_with_named_keys(
+        name,
+        symbol,
+        decimals,
+        total_supply,
+        chain_name,
+        FEE_TOKEN_CONTRACT_KEY_NAME,
+        FEE_TOKEN_CONTRACT_PACKAGE_KEY_NAME,
+        fee_token_entry_points(),
+        named_keys,
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn name() {
+    let name = ERC20::default().name();
+    runtime::ret(CLValue::from_t(name).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn symbol() {
+    let symbol = ERC20::default().symbol();
+    runtime::ret(CLValue::from_t(symbol).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn decimals() {
+    let decimals = ERC20::default().decimals();
+    runtime::ret(CLValue::from_t(decimals).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    let total_supply = ERC20::default().total_supply();
+    runtime::ret(CLValue::from_t(total_supply).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let balance = ERC20::default().balance_of(address);
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    let mut token = ERC20::default();
+    token.transfer(recipient, amount).unwrap_or_revert();
+    token
+        .burn(recipient, transfer_fee(amount))
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    let mut token = ERC20::default();
+    token
+        .transfer_from(owner, recipient, amount)
+        .unwrap_or_revert();
+    token
+        .burn(recipient, transfer_fee(amount))
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC20::default().approve(spender, amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn allowance() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount = ERC20::default().allowance(owner, spender);
+    runtime::ret(CLValue::from_t(amount).unwrap_or_revert());
+}
+
+/// Returns the fee charged on a transfer of `amount`.
+fn transfer_fee(amount: U256) -> U256 {
+    let transfer_fee_uref = runtime::get_key(TRANSFER_FEE_KEY_NAME)
+        .and_then(Key::into_uref)
+        .unwrap_or_revert();
+    let transfer_fee: u32 = storage::read(transfer_fee_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    amount * U256::from(transfer_fee) / U256::from(FEE_DENOMINATOR)
+}
+
+/// Returns the ERC20 entry points without the faucet `mint`.
+fn fee_token_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(entry_points::name());
+    entry_points.add_entry_point(entry_points::symbol());
+    entry_points.add_entry_point(entry_points::decimals());
+    entry_points.add_entry_point(entry_points::total_supply());
+    entry_points.add_entry_point(entry_points::balance_of());
+    entry_points.add_entry_point(entry_points::transfer());
+    entry_points.add_entry_point(entry_points::approve());
+    entry_points.add_entry_point(entry_points::allowance());
+    entry_points.add_entry_point(entry_points::transfer_from());
+    entry_points
+}
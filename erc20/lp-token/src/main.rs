@@ -0,0 +1,248 @@
+//! ERC20 token representing liquidity in the bridge pool. Only the `minter`, the bridge pool
+//! contract package, can mint and burn it, and it is told about every transfer so the reward
+//! shares of the liquidity move along with the tokens.
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::{string::String, vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc20::{
+    constants::{
+        ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, CHAIN_NAME_RUNTIME_ARG_NAME,
+        DECIMALS_RUNTIME_ARG_NAME, NAME_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME,
+        RECIPIENT_RUNTIME_ARG_NAME, SPENDER_RUNTIME_ARG_NAME, SYMBOL_RUNTIME_ARG_NAME,
+    },
+    entry_points, Address, Error, ERC20,
+};
+use casper_types::{
+    contracts::NamedKeys, runtime_args, system::CallStackElement, CLType, CLTyped, CLValue,
+    ContractPackageHash, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
+    RuntimeArgs, U256,
+};
+
+const MINTER_RUNTIME_ARG_NAME: &str = "minter";
+const MINTER_KEY_NAME: &str = "minter";
+const MINT_ENTRY_POINT_NAME: &str = "mint";
+const BURN_ENTRY_POINT_NAME: &str = "burn";
+const TRANSFER_REWARD_SHARES_ENTRY_POINT_NAME: &str = "transfer_reward_shares";
+const LP_TOKEN_CONTRACT_KEY_NAME: &str = "lp_token_contract";
+const LP_TOKEN_CONTRACT_PACKAGE_KEY_NAME: &str = "lp_token_contract_package_hash";
+
+/// Error returned when someone else than the minter tries to mint or burn.
+const ERROR_NOT_MINTER: u16 = 1;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let name: String = runtime::get_named_arg(NAME_RUNTIME_ARG_NAME);
+    let symbol: String = runtime::get_named_arg(SYMBOL_RUNTIME_ARG_NAME);
+    let decimals: u8 = runtime::get_named_arg(DECIMALS_RUNTIME_ARG_NAME);
+    let chain_name: String = runtime::get_named_arg(CHAIN_NAME_RUNTIME_ARG_NAME);
+    let minter: Key = runtime::get_named_arg(MINTER_RUNTIME_ARG_NAME);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        String::from(MINTER_KEY_NAME),
+        storage::new_uref(minter).into_read().into(),
+    );
+
+    let _token = ERC20::install_with_named_keys(
+        name,
+        symbol,
+        decimals,
+        U256::zero(),
+        chain_name,
+        LP_TOKEN_CONTRACT_KEY_NAME,
+        LP_TOKEN_CONTRACT_PACKAGE_KEY_NAME,
+        lp_token_entry_points(),
+        named_keys,
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn name() {
+    let name = ERC20::default().name();
+    runtime::ret(CLValue::from_t(name).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn symbol() {
+    let symbol = ERC20::default().symbol();
+    runtime::ret(CLValue::from_t(symbol).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn decimals() {
+    let decimals = ERC20::default().decimals();
+    runtime::ret(CLValue::from_t(decimals).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    let total_supply = ERC20::default().total_supply();
+    runtime::ret(CLValue::from_t(total_supply).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let balance = ERC20::default().balance_of(address);
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let owner = get_immediate_caller_address().unwrap_or_revert();
+
+    ERC20::default()
+        .transfer(recipient, amount)
+        .unwrap_or_revert();
+    transfer_reward_shares(owner, recipient, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC20::default()
+        .transfer_from(owner, recipient, amount)
+        .unwrap_or_revert();
+    transfer_reward_shares(owner, recipient, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC20::default().approve(spender, amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn allowance() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount = ERC20::default().allowance(owner, spender);
+    runtime::ret(CLValue::from_t(amount).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn mint() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    check_minter();
+    ERC20::default().mint(owner, amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn burn() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    check_minter();
+    ERC20::default().burn(owner, amount).unwrap_or_revert();
+}
+
+/// Returns the minter the token was installed with.
+fn get_minter() -> Key {
+    let minter_uref = runtime::get_key(MINTER_KEY_NAME)
+        .and_then(Key::into_uref)
+        .unwrap_or_revert();
+    storage::read(minter_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+/// Reverts unless the immediate caller is the minter the token was installed with.
+fn check_minter() {
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    if Key::from(caller) != get_minter() {
+        runtime::revert(Error::User(ERROR_NOT_MINTER));
+    }
+}
+
+/// Tells the bridge pool minting the token that `amount` of it moved from `owner` to
+/// `recipient`, so it moves their reward shares as well.
+fn transfer_reward_shares(owner: Address, recipient: Address, amount: U256) {
+    if let Key::Hash(minter_package_hash) = get_minter() {
+        runtime::call_versioned_contract::<()>(
+            ContractPackageHash::new(minter_package_hash),
+            None,
+            TRANSFER_REWARD_SHARES_ENTRY_POINT_NAME,
+            runtime_args! {
+                OWNER_RUNTIME_ARG_NAME => owner,
+                RECIPIENT_RUNTIME_ARG_NAME => recipient,
+                AMOUNT_RUNTIME_ARG_NAME => amount,
+            },
+        );
+    }
+}
+
+/// Gets the immediate call stack element of the current execution.
+fn get_immediate_call_stack_item() -> Option<CallStackElement> {
+    let call_stack = runtime::get_call_stack();
+    call_stack.into_iter().rev().nth(1)
+}
+
+fn call_stack_element_to_address(call_stack_element: CallStackElement) -> Address {
+    match call_stack_element {
+        CallStackElement::Session { account_hash } => Address::from(account_hash),
+        CallStackElement::StoredSession { account_hash, .. } => Address::from(account_hash),
+        CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        } => Address::from(contract_package_hash),
+    }
+}
+
+fn get_immediate_caller_address() -> Result<Address, Error> {
+    get_immediate_call_stack_item()
+        .map(call_stack_element_to_address)
+        .ok_or(Error::InvalidContext)
+}
+
+/// Returns the `mint` or `burn` entry point.
+fn minter_entry_point(name: &str) -> EntryPoint {
+    EntryPoint::new(
+        String::from(name),
+        vec![
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the ERC20 entry points without the faucet `mint`, plus the minter only `mint` and
+/// `burn`.
+fn lp_token_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(entry_points::name());
+    entry_points.add_entry_point(entry_points::symbol());
+    entry_points.add_entry_point(entry_points::decimals());
+    entry_points.add_entry_point(entry_points::total_supply());
+    entry_points.add_entry_point(entry_points::balance_of());
+    entry_points.add_entry_point(entry_points::transfer());
+    entry_points.add_entry_point(entry_points::approve());
+    entry_points.add_entry_point(entry_points::allowance());
+    entry_points.add_entry_point(entry_points::transfer_from());
+    entry_points.add_entry_point(minter_entry_point(MINT_ENTRY_POINT_NAME));
+    entry_points.add_entry_point(minter_entry_point(BURN_ENTRY_POINT_NAME));
+    entry_points
+}
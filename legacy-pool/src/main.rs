@@ -0,0 +1,265 @@
+//! Bridge pool installed the way the first release did it, with its entries written under string
+//! keys, used by the integration tests to upgrade a pool holding the shipped storage layout.
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::{
+    borrow::ToOwned,
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    runtime_args, ApiError, CLType, CLTyped, ContractPackageHash, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs, URef, U256,
+};
+
+const CONTRACT_VERSION_KEY: &str = "version";
+const CONTRACT_KEY: &str = "bridge_pool";
+const BRIDGE_POOL_CONTRACT_PACKAGE_HASH: &str = "bridge_pool_contract_package_hash";
+const BRIDGE_POOL_CONTRACT_HASH: &str = "bridge_pool_contract_hash";
+const BRIDGE_POOL_PACKAGE_NAME: &str = "bridge_pool_package_name";
+const BRIDGE_POOL_ACCESS_UREF: &str = "bridge_pool_access_uref";
+const CONSTRUCTOR_GROUP: &str = "constructor_group";
+const ADMIN_GROUP: &str = "admin_group";
+const ADMIN_ACCESS_UREF: &str = "admin_access_uref";
+
+const CONSTRUCTOR: &str = "constructor";
+const ADD_LIQUIDITY: &str = "add_liquidity";
+const ALLOW_TARGET: &str = "allow_target";
+const ADD_SIGNER: &str = "add_signer";
+const TRANSFER_FROM: &str = "transfer_from";
+
+const AMOUNT: &str = "amount";
+const TOKEN_ADDRESS: &str = "token_address";
+const TOKEN_NAME: &str = "token_name";
+const TARGET_NETWORK: &str = "target_network";
+const TARGET_TOKEN: &str = "target_token";
+const SIGNER: &str = "signer";
+const OWNER: &str = "owner";
+const RECIPIENT: &str = "recipient";
+
+const ACCOUNT_HASH_LIQUIDITIES_DICT: &str = "account_hash_liquidities_dict";
+const HASH_ADDR_LIQUIDITIES_DICT: &str = "hash_addr_liquidities_dict";
+const ALLOWED_TARGETS_DICT: &str = "allowed_targets_dict";
+const USED_HASHES_DICT: &str = "used_hashes_dict";
+const SIGNERS_DICT: &str = "signers_dict";
+const TOKEN_CONTRACT_PACKAGE_HASH_DICT_NAME: &str = "token_contract_package_hash_dict_name";
+
+/// Returns the uref of the named dictionary.
+fn get_dict(name: &str) -> URef {
+    runtime::get_key(name)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert()
+}
+
+fn get_token_arg() -> ContractPackageHash {
+    ContractPackageHash::from_formatted_str(&runtime::get_named_arg::<String>(TOKEN_ADDRESS))
+        .unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+#[no_mangle]
+pub extern "C" fn constructor() {
+    runtime::put_key(
+        BRIDGE_POOL_CONTRACT_PACKAGE_HASH,
+        runtime::get_named_arg::<Key>(BRIDGE_POOL_CONTRACT_PACKAGE_HASH),
+    );
+    for name in [
+        ACCOUNT_HASH_LIQUIDITIES_DICT,
+        HASH_ADDR_LIQUIDITIES_DICT,
+        ALLOWED_TARGETS_DICT,
+        USED_HASHES_DICT,
+        SIGNERS_DICT,
+        TOKEN_CONTRACT_PACKAGE_HASH_DICT_NAME,
+    ] {
+        storage::new_dictionary(name).unwrap_or_revert();
+    }
+}
+
+// the liquidity of a token lived in a dictionary named after the token, keyed on the account hash
+#[no_mangle]
+pub extern "C" fn add_liquidity() {
+    let token = get_token_arg();
+    let amount = runtime::get_named_arg::<U256>(AMOUNT);
+    let caller = runtime::get_caller();
+
+    runtime::call_versioned_contract::<()>(
+        token,
+        None,
+        TRANSFER_FROM,
+        runtime_args! {
+            OWNER => Key::Account(caller),
+            RECIPIENT => runtime::get_key(BRIDGE_POOL_CONTRACT_PACKAGE_HASH).unwrap_or_revert(),
+            AMOUNT => amount,
+        },
+    );
+
+    let clients_dict_name = token.to_formatted_string();
+    let liquidities_dict = get_dict(ACCOUNT_HASH_LIQUIDITIES_DICT);
+    if storage::dictionary_get::<String>(liquidities_dict, &clients_dict_name)
+        .unwrap_or_revert()
+        .is_none()
+    {
+        storage::new_dictionary(&clients_dict_name).unwrap_or_revert();
+        storage::dictionary_put(
+            liquidities_dict,
+            &clients_dict_name,
+            clients_dict_name.clone(),
+        );
+    }
+    let clients_dict = get_dict(&clients_dict_name);
+    let client = caller.to_string();
+    let liquidity = storage::dictionary_get::<U256>(clients_dict, &client)
+        .unwrap_or_revert()
+        .unwrap_or_default();
+    storage::dictionary_put(clients_dict, &client, liquidity + amount);
+}
+
+// the target tokens lived in a dictionary named after the token name, keyed on the target network
+#[no_mangle]
+pub extern "C" fn allow_target() {
+    let token = get_token_arg();
+    let token_name = runtime::get_named_arg::<String>(TOKEN_NAME);
+    let target_network = runtime::get_named_arg::<U256>(TARGET_NETWORK);
+    let target_token = runtime::get_named_arg::<String>(TARGET_TOKEN);
+
+    storage::dictionary_put(
+        get_dict(TOKEN_CONTRACT_PACKAGE_HASH_DICT_NAME),
+        &token.to_string(),
+        token_name.clone(),
+    );
+    let target_token_dict_name = ALLOWED_TARGETS_DICT.to_owned() + token_name.as_str();
+    let allowed_targets_dict = get_dict(ALLOWED_TARGETS_DICT);
+    if storage::dictionary_get::<String>(allowed_targets_dict, &target_token_dict_name)
+        .unwrap_or_revert()
+        .is_none()
+    {
+        storage::new_dictionary(&target_token_dict_name).unwrap_or_revert();
+        storage::dictionary_put(
+            allowed_targets_dict,
+            &target_token_dict_name,
+            target_token_dict_name.clone(),
+        );
+    }
+    storage::dictionary_put(
+        get_dict(&target_token_dict_name),
+        &target_network.to_string(),
+        target_token,
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn add_signer() {
+    storage::dictionary_put(
+        get_dict(SIGNERS_DICT),
+        &runtime::get_named_arg::<String>(SIGNER),
+        true,
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    let admin_group = Group::new(ADMIN_GROUP);
+
+    entry_points.add_entry_point(EntryPoint::new(
+        CONSTRUCTOR,
+        vec![Parameter::new(
+            BRIDGE_POOL_CONTRACT_PACKAGE_HASH,
+            Key::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![Group::new(CONSTRUCTOR_GROUP)]),
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        ADD_LIQUIDITY,
+        vec![
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        ALLOW_TARGET,
+        vec![
+            Parameter::new(TOKEN_ADDRESS, String::cl_type()),
+            Parameter::new(TOKEN_NAME, String::cl_type()),
+            Parameter::new(TARGET_NETWORK, U256::cl_type()),
+            Parameter::new(TARGET_TOKEN, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group.clone()]),
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        ADD_SIGNER,
+        vec![Parameter::new(SIGNER, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![admin_group]),
+        EntryPointType::Contract,
+    ));
+
+    let (contract_hash, contract_version) = storage::new_contract(
+        entry_points,
+        None,
+        Some(BRIDGE_POOL_PACKAGE_NAME.to_string()),
+        Some(BRIDGE_POOL_ACCESS_UREF.to_string()),
+    );
+    runtime::put_key(BRIDGE_POOL_CONTRACT_HASH, contract_hash.into());
+
+    let package_hash = ContractPackageHash::new(
+        runtime::get_key(BRIDGE_POOL_PACKAGE_NAME)
+            .unwrap_or_revert()
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let package_hash_key: Key = package_hash.into();
+
+    let constructor_access =
+        storage::create_contract_user_group(package_hash, CONSTRUCTOR_GROUP, 1, Default::default())
+            .unwrap_or_revert()
+            .pop()
+            .unwrap_or_revert();
+    runtime::call_contract::<()>(
+        contract_hash,
+        CONSTRUCTOR,
+        runtime_args! {
+            BRIDGE_POOL_CONTRACT_PACKAGE_HASH => package_hash_key,
+        },
+    );
+    let mut urefs = BTreeSet::new();
+    urefs.insert(constructor_access);
+    storage::remove_contract_user_group_urefs(package_hash, CONSTRUCTOR_GROUP, urefs)
+        .unwrap_or_revert();
+
+    let admin_access =
+        storage::create_contract_user_group(package_hash, ADMIN_GROUP, 1, Default::default())
+            .unwrap_or_revert()
+            .pop()
+            .unwrap_or_revert();
+    runtime::put_key(ADMIN_ACCESS_UREF, admin_access.into());
+
+    runtime::put_key(BRIDGE_POOL_CONTRACT_PACKAGE_HASH, package_hash_key);
+    runtime::put_key(
+        CONTRACT_VERSION_KEY,
+        storage::new_uref(contract_version).into(),
+    );
+    runtime::put_key(CONTRACT_KEY, contract_hash.into());
+}
@@ -0,0 +1,69 @@
+//! Session code funding a new purse from the main purse of the account and passing it to
+//! `add_liquidity_native` or `swap_native` of the bridge pool.
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{runtime_args, ApiError, ContractPackageHash, Key, RuntimeArgs, U256, U512};
+
+const BRIDGE_POOL_CONTRACT_PACKAGE_HASH: &str = "bridge_pool_contract_package_hash";
+const ENTRY_POINT: &str = "entry_point";
+const SWAP_NATIVE: &str = "swap_native";
+
+const AMOUNT: &str = "amount";
+const PURSE: &str = "purse";
+const TARGET_NETWORK: &str = "target_network";
+const TARGET_TOKEN: &str = "target_token";
+const TARGET_ADDRESS: &str = "target_address";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let bridge_pool = match runtime::get_named_arg::<Key>(BRIDGE_POOL_CONTRACT_PACKAGE_HASH) {
+        Key::Hash(hash) => ContractPackageHash::new(hash),
+        _ => runtime::revert(ApiError::UnexpectedKeyVariant),
+    };
+    let entry_point = runtime::get_named_arg::<String>(ENTRY_POINT);
+    let amount = runtime::get_named_arg::<U256>(AMOUNT);
+
+    let mut bytes = [0u8; 32];
+    amount.to_little_endian(&mut bytes);
+    let purse = system::create_purse();
+    system::transfer_from_purse_to_purse(
+        account::get_main_purse(),
+        purse,
+        U512::from_little_endian(&bytes),
+        None,
+    )
+    .unwrap_or_revert();
+
+    let mut args = runtime_args! {
+        AMOUNT => amount,
+        PURSE => purse,
+    };
+    if entry_point == SWAP_NATIVE {
+        args.insert(
+            TARGET_NETWORK,
+            runtime::get_named_arg::<U256>(TARGET_NETWORK),
+        )
+        .unwrap_or_revert();
+        args.insert(TARGET_TOKEN, runtime::get_named_arg::<String>(TARGET_TOKEN))
+            .unwrap_or_revert();
+        args.insert(
+            TARGET_ADDRESS,
+            runtime::get_named_arg::<String>(TARGET_ADDRESS),
+        )
+        .unwrap_or_revert();
+    }
+
+    runtime::call_versioned_contract::<()>(bridge_pool, None, &entry_point, args);
+}
@@ -22,7 +22,8 @@ mod tests {
     use casper_types::{
         account::AccountHash,
         bytesrepr::{FromBytes, ToBytes},
-        CLTyped, ContractPackageHash, Key, URef, BLAKE2B_DIGEST_LENGTH, U256,
+        CLTyped, ContractPackageHash, Key, PublicKey, SecretKey, URef, BLAKE2B_DIGEST_LENGTH, U256,
+        U512,
     };
     use test_env::TestEnv;
 
@@ -31,6 +32,20 @@ mod tests {
     const COUNTER_CALL_WASM: &str = "counter-call.wasm"; // The session code that calls the contract
     const ERC20_CONTRACT_NAME: &str = "erc20_token_contract";
     const ERC20_CONTRACT_PACKAGE_HASH: &str = "erc20-contract_package_hash";
+    const LP_TOKEN_WASM: &str = "lp_token.wasm";
+    const LP_TOKEN_CONTRACT_NAME: &str = "lp_token_contract";
+    const LP_TOKEN_CONTRACT_PACKAGE_HASH: &str = "lp_token_contract_package_hash";
+    const CEP18_TOKEN_WASM: &str = "cep18_token.wasm";
+    const CEP18_TOKEN_CONTRACT_NAME: &str = "cep18_token_contract";
+    const CEP18_TOKEN_CONTRACT_PACKAGE_HASH: &str = "cep18_token_contract_package_hash";
+    const FEE_TOKEN_WASM: &str = "fee_token.wasm";
+    const FEE_TOKEN_CONTRACT_NAME: &str = "fee_token_contract";
+    const FEE_TOKEN_CONTRACT_PACKAGE_HASH: &str = "fee_token_contract_package_hash";
+    const BRIDGE_CALLER_WASM: &str = "bridge-caller.wasm"; // Stored contract that calls the bridge pool
+    const BRIDGE_CALLER_CONTRACT_NAME: &str = "bridge_caller_contract";
+    const BRIDGE_CALLER_CONTRACT_PACKAGE_HASH: &str = "bridge_caller_contract_package_hash";
+    const NATIVE_CALL_WASM: &str = "native-call.wasm"; // Session code that passes a purse to the contract
+    const LEGACY_POOL_WASM: &str = "legacy-pool.wasm"; // Bridge pool storing its entries under string keys
     const BRIDGE_POOL_CONTRACT_HASH: &str = "bridge_pool_contract_hash";
     const BRIDGE_POOL_CONTRACT_PACKAGE_HASH: &str = "bridge_pool_package_name";
 
@@ -38,6 +53,9 @@ mod tests {
     const LIQUIDITY_KEY: &str = "liquidity"; // Named key referencing the count value
     const CONTRACT_VERSION_KEY: &str = "version"; // Automatically incremented version in a contract package
     const ALLOWANCES_SEED_UREF: &str = "allowances";
+    const CHAIN_NAME: &str = "casper-test";
+    const WITHDRAW_DOMAIN_VERSION: &str = "1";
+    const PERMIT_VERSION: &str = "1";
 
     #[test]
     fn should_be_able_to_install_and_add_liquidity() {
@@ -49,6 +67,7 @@ mod tests {
         let erc20_runtime_args = runtime_args! {
             "name" => "FERRUM_ERC20".to_string(),
             "symbol" => "F_ERC20".to_string(),
+            "chain_name" => CHAIN_NAME.to_string(),
             "total_supply" => U256::from(500000i64),
             "decimals" => 8u8,
         };
@@ -90,7 +109,9 @@ mod tests {
         let contract_installation_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
             BRIDGE_POOL_WASM,
-            runtime_args! {},
+            runtime_args! {
+                "chain_name" => CHAIN_NAME.to_string(),
+            },
         )
         .build();
 
@@ -177,6 +198,7 @@ mod tests {
         let erc20_runtime_args = runtime_args! {
             "name" => "FERRUM_ERC20".to_string(),
             "symbol" => "F_ERC20".to_string(),
+            "chain_name" => CHAIN_NAME.to_string(),
             "total_supply" => U256::from(500000i64),
             "decimals" => 8u8,
         };
@@ -218,7 +240,9 @@ mod tests {
         let contract_installation_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
             BRIDGE_POOL_WASM,
-            runtime_args! {},
+            runtime_args! {
+                "chain_name" => CHAIN_NAME.to_string(),
+            },
         )
         .build();
 
@@ -313,6 +337,45 @@ mod tests {
             .commit();
     }
 
+    #[test]
+    fn should_get_the_liquidity_of_any_owner() {
+        let mut pool = install_pool_with_liquidity(U256::from(9i64));
+        let token_address = pool.erc20_contract_package_hash.to_formatted_string();
+        let bridge_pool_contract_key: Key = pool.bridge_pool_contract_package_hash.into();
+
+        // the liquidity of the caller, of any other account or of a contract package can be
+        // looked up
+        for owner in [
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            Key::Account(AccountHash::new([7u8; 32])),
+            bridge_pool_contract_key,
+        ] {
+            call_bridge_pool(
+                &mut pool,
+                "get_liquidity_of",
+                runtime_args! {
+                    "token_address" => token_address.clone(),
+                    "owner" => owner,
+                },
+            )
+            .expect_success()
+            .commit();
+        }
+
+        // keys of anything else do not own liquidity
+        let pool_purse = get_pool_purse(&pool.builder, pool.bridge_pool_contract_hash);
+        call_bridge_pool(
+            &mut pool,
+            "get_liquidity_of",
+            runtime_args! {
+                "token_address" => token_address,
+                "owner" => Key::URef(pool_purse),
+            },
+        )
+        .expect_failure()
+        .commit();
+    }
+
     #[test]
     fn should_be_able_to_install_and_add_liquidity_and_remove_liquidity() {
         let mut builder = InMemoryWasmTestBuilder::default();
@@ -323,6 +386,7 @@ mod tests {
         let erc20_runtime_args = runtime_args! {
             "name" => "FERRUM_ERC20".to_string(),
             "symbol" => "F_ERC20".to_string(),
+            "chain_name" => CHAIN_NAME.to_string(),
             "total_supply" => U256::from(500000i64),
             "decimals" => 8u8,
         };
@@ -364,7 +428,9 @@ mod tests {
         let contract_installation_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
             BRIDGE_POOL_WASM,
-            runtime_args! {},
+            runtime_args! {
+                "chain_name" => CHAIN_NAME.to_string(),
+            },
         )
         .build();
 
@@ -470,6 +536,7 @@ mod tests {
         let erc20_runtime_args = runtime_args! {
             "name" => "FERRUM_ERC20".to_string(),
             "symbol" => "F_ERC20".to_string(),
+            "chain_name" => CHAIN_NAME.to_string(),
             "total_supply" => U256::from(500000i64),
             "decimals" => 8u8,
         };
@@ -511,7 +578,9 @@ mod tests {
         let contract_installation_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
             BRIDGE_POOL_WASM,
-            runtime_args! {},
+            runtime_args! {
+                "chain_name" => CHAIN_NAME.to_string(),
+            },
         )
         .build();
 
@@ -580,6 +649,7 @@ mod tests {
         let erc20_runtime_args = runtime_args! {
             "name" => "FERRUM_ERC20".to_string(),
             "symbol" => "F_ERC20".to_string(),
+            "chain_name" => CHAIN_NAME.to_string(),
             "total_supply" => U256::from(500000i64),
             "decimals" => 8u8,
         };
@@ -621,7 +691,9 @@ mod tests {
         let contract_installation_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
             BRIDGE_POOL_WASM,
-            runtime_args! {},
+            runtime_args! {
+                "chain_name" => CHAIN_NAME.to_string(),
+            },
         )
         .build();
 
@@ -707,6 +779,7 @@ mod tests {
         let erc20_runtime_args = runtime_args! {
             "name" => "FERRUM_ERC20".to_string(),
             "symbol" => "F_ERC20".to_string(),
+            "chain_name" => CHAIN_NAME.to_string(),
             "total_supply" => U256::from(500000i64),
             "decimals" => 8u8,
         };
@@ -748,7 +821,9 @@ mod tests {
         let contract_installation_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
             BRIDGE_POOL_WASM,
-            runtime_args! {},
+            runtime_args! {
+                "chain_name" => CHAIN_NAME.to_string(),
+            },
         )
         .build();
 
@@ -826,6 +901,7 @@ mod tests {
         let amount = U256::from(1i64);
         let payee = "0Bdb79846e8331A19A65430363f240Ec8aCC2A52".to_string();
         let token_recipient = "qwe".to_string();
+        let expiry = 1_000_000u64;
 
         let salt_array: [u8; 32] = hex::decode(salt_string.clone())
             .unwrap()
@@ -834,14 +910,22 @@ mod tests {
 
         let caller: String = (*DEFAULT_ACCOUNT_ADDR).to_string();
 
+        let domain_separator = contract_utils::keccak::domain_separator(
+            bridge_pool_contract_package_hash.value(),
+            CHAIN_NAME,
+            WITHDRAW_DOMAIN_VERSION,
+        );
+
         let message_hash = contract_utils::keccak::message_hash(
-            erc20_contract_package_hash_string.clone(),
+            domain_separator,
+            erc20_contract_package_hash.value(),
             payee.clone(),
-            amount.to_string(),
+            amount,
             caller.clone(),
             chain_id,
             salt_array,
             token_recipient.clone(),
+            expiry,
         );
 
         let private_key_str = "a7a08a23f69090a53a32814da1d262c8d2728d16bce420ae143978d85a06be49";
@@ -908,6 +992,51 @@ mod tests {
 
         builder.exec(check_signer_request).expect_success().commit();
 
+        // a signature made for the same withdrawal in another domain must not be accepted
+        let other_domain_message_hash = contract_utils::keccak::message_hash(
+            contract_utils::keccak::domain_separator(
+                bridge_pool_contract_package_hash.value(),
+                "casper",
+                WITHDRAW_DOMAIN_VERSION,
+            ),
+            erc20_contract_package_hash.value(),
+            payee.clone(),
+            amount,
+            caller.clone(),
+            chain_id,
+            salt_array,
+            token_recipient.clone(),
+            expiry,
+        );
+
+        let other_domain_signature = contract_utils::keccak::ecdsa_sign(
+            &hex::decode(other_domain_message_hash).unwrap(),
+            &private_key_bytes,
+        );
+
+        let other_domain_withdraw_signed_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "withdraw_signed",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash_string.clone(),
+                "payee" => payee.clone(),
+                "amount" => amount,
+                "chain_id" => chain_id,
+                "salt" => salt_string.clone(),
+                "signatures" => vec![hex::encode(other_domain_signature)],
+                "token_recipient" => token_recipient.clone(),
+                "caller" => caller.clone(),
+                "expiry" => expiry,
+            },
+        )
+        .build();
+
+        builder
+            .exec(other_domain_withdraw_signed_request)
+            .expect_failure()
+            .commit();
+
         let signature_string: String = hex::encode(signature_pre);
 
         let withdraw_signed_args = runtime_args! {
@@ -916,17 +1045,405 @@ mod tests {
             "amount" => amount,
             "chain_id" => chain_id,
             "salt" => salt_string,
-            "signature" => signature_string,
+            "signatures" => vec![signature_string],
             "token_recipient" => token_recipient,
             "caller" => caller,
+            "expiry" => expiry,
         };
 
+        // the authorization can no longer be used once its expiry has passed
+        let expired_withdraw_signed_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "withdraw_signed",
+            withdraw_signed_args.clone(),
+        )
+        .with_block_time(expiry + 1)
+        .build();
+
+        builder
+            .exec(expired_withdraw_signed_request)
+            .expect_failure()
+            .commit();
+
         let withdraw_signed_request = ExecuteRequestBuilder::contract_call_by_hash(
             *DEFAULT_ACCOUNT_ADDR,
             bridge_pool_contract_hash,
             "withdraw_signed",
             withdraw_signed_args,
         )
+        .with_block_time(expiry)
+        .build();
+
+        builder
+            .exec(withdraw_signed_request)
+            .expect_success()
+            .commit();
+    }
+
+    #[test]
+    fn should_rate_limit_withdraw_signed() {
+        let mut pool = install_pool_with_liquidity(U256::from(9i64));
+        allow_target(&mut pool);
+        let token_address = pool.erc20_contract_package_hash.to_formatted_string();
+
+        let expiry = 1_000_000u64;
+        let amount = U256::from(2i64);
+        let (message_hash, withdraw_signed_args, signer) = sign_withdrawal(
+            &pool,
+            amount,
+            "6b166cc8016d4ddb7a2578245ac9de73bd95f30ea960ab53dec02141623832dd",
+            expiry,
+        );
+        let (_, other_withdraw_signed_args, _) = sign_withdrawal(
+            &pool,
+            amount,
+            "7b166cc8016d4ddb7a2578245ac9de73bd95f30ea960ab53dec02141623832dd",
+            expiry,
+        );
+        add_signer(&mut pool, signer);
+
+        // only registered tokens can be limited, and only limits that were set can be removed
+        let args = runtime_args! {
+            "token_address" => pool.bridge_pool_contract_package_hash.to_formatted_string(),
+            "limit" => amount,
+            "window" => 3_600_000u64,
+        };
+        call_bridge_pool(&mut pool, "set_rate_limit", args)
+            .expect_failure()
+            .commit();
+
+        call_bridge_pool(
+            &mut pool,
+            "remove_rate_limit",
+            runtime_args! {
+                "token_address" => token_address.clone(),
+            },
+        )
+        .expect_failure()
+        .commit();
+
+        // nothing can be withdrawn while the rate limit of the token is exhausted
+        let set_rate_limit_args = |limit: U256| {
+            runtime_args! {
+                "token_address" => token_address.clone(),
+                "limit" => limit,
+                "window" => 3_600_000u64,
+            }
+        };
+        call_bridge_pool_at(
+            &mut pool,
+            "set_rate_limit",
+            set_rate_limit_args(U256::zero()),
+            expiry,
+        )
+        .expect_success()
+        .commit();
+
+        call_bridge_pool_at(
+            &mut pool,
+            "withdraw_signed",
+            withdraw_signed_args.clone(),
+            expiry,
+        )
+        .expect_failure()
+        .commit();
+
+        call_bridge_pool_at(
+            &mut pool,
+            "set_rate_limit",
+            set_rate_limit_args(amount),
+            expiry,
+        )
+        .expect_success()
+        .commit();
+
+        // a time locked withdrawal uses up the limit as soon as it is queued
+        call_bridge_pool(
+            &mut pool,
+            "set_withdrawal_delay",
+            runtime_args! {
+                "token_address" => token_address.clone(),
+                "threshold" => U256::one(),
+                "delay" => 1000u64,
+            },
+        )
+        .expect_success()
+        .commit();
+
+        call_bridge_pool_at(&mut pool, "withdraw_signed", withdraw_signed_args, expiry)
+            .expect_success()
+            .commit();
+
+        call_bridge_pool_at(
+            &mut pool,
+            "withdraw_signed",
+            other_withdraw_signed_args.clone(),
+            expiry,
+        )
+        .expect_failure()
+        .commit();
+
+        // and gives it back when it is vetoed
+        call_bridge_pool_at(
+            &mut pool,
+            "veto_withdrawal",
+            runtime_args! {
+                "message_hash" => message_hash,
+            },
+            expiry,
+        )
+        .expect_success()
+        .commit();
+
+        call_bridge_pool_at(
+            &mut pool,
+            "withdraw_signed",
+            other_withdraw_signed_args,
+            expiry,
+        )
+        .expect_success()
+        .commit();
+
+        let remove_rate_limit_args = runtime_args! {
+            "token_address" => token_address,
+        };
+        call_bridge_pool(
+            &mut pool,
+            "remove_rate_limit",
+            remove_rate_limit_args.clone(),
+        )
+        .expect_success()
+        .commit();
+
+        call_bridge_pool(&mut pool, "remove_rate_limit", remove_rate_limit_args)
+            .expect_failure()
+            .commit();
+    }
+
+    #[test]
+    fn should_be_able_to_withdraw_signed_only_when_threshold_is_met() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let erc20_runtime_args = runtime_args! {
+            "name" => "FERRUM_ERC20".to_string(),
+            "symbol" => "F_ERC20".to_string(),
+            "chain_name" => CHAIN_NAME.to_string(),
+            "total_supply" => U256::from(500000i64),
+            "decimals" => 8u8,
+        };
+
+        let erc_20_install_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, ERC20_WASM, erc20_runtime_args)
+                .build();
+
+        builder
+            .exec(erc_20_install_request)
+            .expect_success()
+            .commit();
+
+        let erc20_contract_hash = get_erc20_contract_hash(&builder);
+
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract_hash,
+            "mint",
+            runtime_args! {},
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+
+        let contract_installation_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            BRIDGE_POOL_WASM,
+            runtime_args! {
+                "chain_name" => CHAIN_NAME.to_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(contract_installation_request)
+            .expect_success()
+            .commit();
+
+        let bridge_pool_contract_package_hash = get_bridge_pool_contract_package_hash(&builder);
+
+        let bridge_pool_contract_hash = get_bridge_pool_contract_hash(&builder);
+
+        let bridge_pool_contract_key: Key = bridge_pool_contract_package_hash.into();
+
+        let approve_args = runtime_args! {
+            "spender" => bridge_pool_contract_key,
+            "amount" => U256::from(10i64),
+        };
+
+        let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract_hash,
+            "approve",
+            approve_args,
+        )
+        .build();
+
+        builder.exec(approve_request).expect_success().commit();
+
+        let erc20_contract_package_hash = get_erc20_contract_package_hash(&builder);
+
+        let erc20_contract_package_hash_string = erc20_contract_package_hash.to_formatted_string();
+        let bridge_pool_contract_package_hash_string =
+            bridge_pool_contract_package_hash.to_formatted_string();
+
+        let add_liquidity_args = runtime_args! {
+            "amount" => U256::from(9i64),
+            "token_address" => erc20_contract_package_hash_string.clone(),
+            "bridge_pool_contract_package_hash" => bridge_pool_contract_package_hash_string ,
+        };
+
+        let add_liquidity_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "add_liquidity",
+            add_liquidity_args,
+        )
+        .build();
+
+        builder
+            .exec(add_liquidity_request)
+            .expect_success()
+            .commit();
+
+        let salt_string =
+            "6b166cc8016d4ddb7a2578245ac9de73bd95f30ea960ab53dec02141623832dd".to_string();
+        let chain_id = 1u64;
+        let amount = U256::from(1i64);
+        let payee = "0Bdb79846e8331A19A65430363f240Ec8aCC2A52".to_string();
+        let token_recipient = "qwe".to_string();
+        let expiry = 1_000_000u64;
+
+        let salt_array: [u8; 32] = hex::decode(salt_string.clone())
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        let caller: String = (*DEFAULT_ACCOUNT_ADDR).to_string();
+
+        let domain_separator = contract_utils::keccak::domain_separator(
+            bridge_pool_contract_package_hash.value(),
+            CHAIN_NAME,
+            WITHDRAW_DOMAIN_VERSION,
+        );
+
+        let message_hash = contract_utils::keccak::message_hash(
+            domain_separator,
+            erc20_contract_package_hash.value(),
+            payee.clone(),
+            amount,
+            caller.clone(),
+            chain_id,
+            salt_array,
+            token_recipient.clone(),
+            expiry,
+        );
+
+        let private_keys = [
+            "a7a08a23f69090a53a32814da1d262c8d2728d16bce420ae143978d85a06be49",
+            "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+        ];
+
+        let mut signatures: Vec<String> = Vec::new();
+
+        for private_key_str in private_keys {
+            let signature_pre = contract_utils::keccak::ecdsa_sign(
+                &hex::decode(message_hash.clone()).unwrap(),
+                &hex::decode(private_key_str).unwrap(),
+            );
+
+            let signer_string =
+                hex::encode(signer_unique(message_hash.clone(), signature_pre.to_vec()));
+
+            let add_signer_request = ExecuteRequestBuilder::contract_call_by_hash(
+                *DEFAULT_ACCOUNT_ADDR,
+                bridge_pool_contract_hash,
+                "add_signer",
+                runtime_args! {
+                    "signer" => signer_string,
+                },
+            )
+            .build();
+
+            builder.exec(add_signer_request).expect_success().commit();
+
+            signatures.push(hex::encode(signature_pre));
+        }
+
+        let set_threshold_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "set_threshold",
+            runtime_args! {
+                "threshold" => 2u32,
+            },
+        )
+        .build();
+
+        builder
+            .exec(set_threshold_request)
+            .expect_success()
+            .commit();
+
+        let withdraw_signed_args = |signatures: Vec<String>| {
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash_string.clone(),
+                "payee" => payee.clone(),
+                "amount" => amount,
+                "chain_id" => chain_id,
+                "salt" => salt_string.clone(),
+                "signatures" => signatures,
+                "token_recipient" => token_recipient.clone(),
+                "caller" => caller.clone(),
+                "expiry" => expiry,
+            }
+        };
+
+        // a single signature is below the threshold
+        let withdraw_signed_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "withdraw_signed",
+            withdraw_signed_args(vec![signatures[0].clone()]),
+        )
+        .build();
+
+        builder
+            .exec(withdraw_signed_request)
+            .expect_failure()
+            .commit();
+
+        // the same signer twice does not count towards the threshold
+        let withdraw_signed_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "withdraw_signed",
+            withdraw_signed_args(vec![signatures[0].clone(), signatures[0].clone()]),
+        )
+        .build();
+
+        builder
+            .exec(withdraw_signed_request)
+            .expect_failure()
+            .commit();
+
+        let withdraw_signed_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "withdraw_signed",
+            withdraw_signed_args(signatures),
+        )
         .build();
 
         builder
@@ -943,6 +1460,7 @@ mod tests {
         let erc20_runtime_args = runtime_args! {
             "name" => "FERRUM_ERC20".to_string(),
             "symbol" => "F_ERC20".to_string(),
+            "chain_name" => CHAIN_NAME.to_string(),
             "total_supply" => U256::from(500000i64),
             "decimals" => 8u8,
         };
@@ -984,7 +1502,9 @@ mod tests {
         let contract_installation_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
             BRIDGE_POOL_WASM,
-            runtime_args! {},
+            runtime_args! {
+                "chain_name" => CHAIN_NAME.to_string(),
+            },
         )
         .build();
 
@@ -1056,6 +1576,7 @@ mod tests {
         let erc20_runtime_args = runtime_args! {
             "name" => "FERRUM_ERC20".to_string(),
             "symbol" => "F_ERC20".to_string(),
+            "chain_name" => CHAIN_NAME.to_string(),
             "total_supply" => U256::from(500000i64),
             "decimals" => 8u8,
         };
@@ -1097,7 +1618,9 @@ mod tests {
         let contract_installation_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
             BRIDGE_POOL_WASM,
-            runtime_args! {},
+            runtime_args! {
+                "chain_name" => CHAIN_NAME.to_string(),
+            },
         )
         .build();
 
@@ -1210,11 +1733,2949 @@ mod tests {
         builder.exec(swap_request).expect_success().commit();
     }
 
-    /// Creates a dictionary item key for an (owner, spender) pair.
-    fn make_allowances_dictionary_item_key(owner: Key, spender: Key) -> String {
-        let mut preimage = Vec::new();
-        preimage.append(&mut owner.to_bytes().unwrap());
-        preimage.append(&mut spender.to_bytes().unwrap());
+    #[test]
+    fn should_not_be_able_to_swap_while_swaps_are_paused() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let erc20_runtime_args = runtime_args! {
+            "name" => "FERRUM_ERC20".to_string(),
+            "symbol" => "F_ERC20".to_string(),
+            "chain_name" => CHAIN_NAME.to_string(),
+            "total_supply" => U256::from(500000i64),
+            "decimals" => 8u8,
+        };
+
+        let erc_20_install_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, ERC20_WASM, erc20_runtime_args)
+                .build();
+
+        builder
+            .exec(erc_20_install_request)
+            .expect_success()
+            .commit();
+
+        let erc20_contract_hash = get_erc20_contract_hash(&builder);
+
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract_hash,
+            "mint",
+            runtime_args! {},
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+
+        let contract_installation_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            BRIDGE_POOL_WASM,
+            runtime_args! {
+                "chain_name" => CHAIN_NAME.to_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(contract_installation_request)
+            .expect_success()
+            .commit();
+
+        let bridge_pool_contract_package_hash = get_bridge_pool_contract_package_hash(&builder);
+
+        let bridge_pool_contract_hash = get_bridge_pool_contract_hash(&builder);
+
+        let bridge_pool_contract_key: Key = bridge_pool_contract_package_hash.into();
+
+        let approve_args = runtime_args! {
+            "spender" => bridge_pool_contract_key,
+            "amount" => U256::from(10i64),
+        };
+
+        let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract_hash,
+            "approve",
+            approve_args,
+        )
+        .build();
+
+        builder.exec(approve_request).expect_success().commit();
+
+        let erc20_contract_package_hash = get_erc20_contract_package_hash(&builder);
+
+        let allow_target_args = runtime_args! {
+            "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            "token_name" => "some_unusual_token_name".to_string() ,
+            "target_token" => "qwe".to_string() ,
+            "target_network" => U256::from(1i64),
+        };
+
+        let allow_target_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "allow_target",
+            allow_target_args,
+        )
+        .build();
+
+        builder.exec(allow_target_request).expect_success().commit();
+
+        let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "pause",
+            runtime_args! {
+                "operation" => "swap".to_string(),
+            },
+        )
+        .build();
+
+        builder.exec(pause_request).expect_success().commit();
+
+        let swap_args = runtime_args! {
+            "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            "target_token" => "qwe".to_string(),
+            "target_address" => "qwe_addr".to_string(),
+            "target_network" => U256::from(1i64),
+            "amount" => U256::from(1i64),
+        };
+
+        let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "swap",
+            swap_args.clone(),
+        )
+        .build();
+
+        builder.exec(swap_request).expect_failure().commit();
+
+        // deposits are still accepted while swaps are paused
+        let add_liquidity_args = runtime_args! {
+            "amount" => U256::from(1i64),
+            "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            "bridge_pool_contract_package_hash" => bridge_pool_contract_package_hash.to_formatted_string(),
+        };
+
+        let add_liquidity_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "add_liquidity",
+            add_liquidity_args,
+        )
+        .build();
+
+        builder
+            .exec(add_liquidity_request)
+            .expect_success()
+            .commit();
+
+        let unpause_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "unpause",
+            runtime_args! {
+                "operation" => "swap".to_string(),
+            },
+        )
+        .build();
+
+        builder.exec(unpause_request).expect_success().commit();
+
+        let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "swap",
+            swap_args,
+        )
+        .build();
+
+        builder.exec(swap_request).expect_success().commit();
+    }
+
+    #[test]
+    fn should_only_be_able_to_remove_liquidity_of_a_disabled_token() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let erc20_runtime_args = runtime_args! {
+            "name" => "FERRUM_ERC20".to_string(),
+            "symbol" => "F_ERC20".to_string(),
+            "chain_name" => CHAIN_NAME.to_string(),
+            "total_supply" => U256::from(500000i64),
+            "decimals" => 8u8,
+        };
+
+        let erc_20_install_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, ERC20_WASM, erc20_runtime_args)
+                .build();
+
+        builder
+            .exec(erc_20_install_request)
+            .expect_success()
+            .commit();
+
+        let erc20_contract_hash = get_erc20_contract_hash(&builder);
+
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract_hash,
+            "mint",
+            runtime_args! {},
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+
+        let contract_installation_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            BRIDGE_POOL_WASM,
+            runtime_args! {
+                "chain_name" => CHAIN_NAME.to_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(contract_installation_request)
+            .expect_success()
+            .commit();
+
+        let bridge_pool_contract_package_hash = get_bridge_pool_contract_package_hash(&builder);
+
+        let bridge_pool_contract_hash = get_bridge_pool_contract_hash(&builder);
+
+        let bridge_pool_contract_key: Key = bridge_pool_contract_package_hash.into();
+
+        let approve_args = runtime_args! {
+            "spender" => bridge_pool_contract_key,
+            "amount" => U256::from(10i64),
+        };
+
+        let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract_hash,
+            "approve",
+            approve_args,
+        )
+        .build();
+
+        builder.exec(approve_request).expect_success().commit();
+
+        let erc20_contract_package_hash = get_erc20_contract_package_hash(&builder);
+
+        let allow_target_args = runtime_args! {
+            "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            "token_name" => "some_unusual_token_name".to_string() ,
+            "target_token" => "qwe".to_string() ,
+            "target_network" => U256::from(1i64),
+        };
+
+        let allow_target_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "allow_target",
+            allow_target_args,
+        )
+        .build();
+
+        builder.exec(allow_target_request).expect_success().commit();
+
+        let add_liquidity_args = runtime_args! {
+            "amount" => U256::from(2i64),
+            "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            "bridge_pool_contract_package_hash" => bridge_pool_contract_package_hash.to_formatted_string(),
+        };
+
+        let add_liquidity_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "add_liquidity",
+            add_liquidity_args.clone(),
+        )
+        .build();
+
+        builder
+            .exec(add_liquidity_request)
+            .expect_success()
+            .commit();
+
+        let disable_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "disable_token",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(disable_token_request)
+            .expect_success()
+            .commit();
+
+        let get_token_status_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "get_token_status",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(get_token_status_request)
+            .expect_success()
+            .commit();
+
+        let swap_args = runtime_args! {
+            "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            "target_token" => "qwe".to_string(),
+            "target_address" => "qwe_addr".to_string(),
+            "target_network" => U256::from(1i64),
+            "amount" => U256::from(1i64),
+        };
+
+        let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "swap",
+            swap_args.clone(),
+        )
+        .build();
+
+        builder.exec(swap_request).expect_failure().commit();
+
+        let add_liquidity_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "add_liquidity",
+            add_liquidity_args,
+        )
+        .build();
+
+        builder
+            .exec(add_liquidity_request)
+            .expect_failure()
+            .commit();
+
+        // liquidity providers can still exit a disabled token
+        let remove_liquidity_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "remove_liquidity",
+            runtime_args! {
+                "amount" => U256::from(1i64),
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(remove_liquidity_request)
+            .expect_success()
+            .commit();
+
+        let enable_token_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "enable_token",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            },
+        )
+        .build();
+
+        builder.exec(enable_token_request).expect_success().commit();
+
+        let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "swap",
+            swap_args,
+        )
+        .build();
+
+        builder.exec(swap_request).expect_success().commit();
+    }
+
+    #[test]
+    fn should_be_able_to_update_and_disallow_a_target() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let erc20_runtime_args = runtime_args! {
+            "name" => "FERRUM_ERC20".to_string(),
+            "symbol" => "F_ERC20".to_string(),
+            "chain_name" => CHAIN_NAME.to_string(),
+            "total_supply" => U256::from(500000i64),
+            "decimals" => 8u8,
+        };
+
+        let erc_20_install_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, ERC20_WASM, erc20_runtime_args)
+                .build();
+
+        builder
+            .exec(erc_20_install_request)
+            .expect_success()
+            .commit();
+
+        let erc20_contract_hash = get_erc20_contract_hash(&builder);
+
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract_hash,
+            "mint",
+            runtime_args! {},
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+
+        let contract_installation_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            BRIDGE_POOL_WASM,
+            runtime_args! {
+                "chain_name" => CHAIN_NAME.to_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(contract_installation_request)
+            .expect_success()
+            .commit();
+
+        let bridge_pool_contract_package_hash = get_bridge_pool_contract_package_hash(&builder);
+
+        let bridge_pool_contract_hash = get_bridge_pool_contract_hash(&builder);
+
+        let bridge_pool_contract_key: Key = bridge_pool_contract_package_hash.into();
+
+        let approve_args = runtime_args! {
+            "spender" => bridge_pool_contract_key,
+            "amount" => U256::from(10i64),
+        };
+
+        let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract_hash,
+            "approve",
+            approve_args,
+        )
+        .build();
+
+        builder.exec(approve_request).expect_success().commit();
+
+        let erc20_contract_package_hash = get_erc20_contract_package_hash(&builder);
+
+        let allow_target_args = runtime_args! {
+            "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            "token_name" => "some_unusual_token_name".to_string() ,
+            "target_token" => "qwe".to_string() ,
+            "target_network" => U256::from(1i64),
+        };
+
+        let allow_target_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "allow_target",
+            allow_target_args,
+        )
+        .build();
+
+        builder.exec(allow_target_request).expect_success().commit();
+
+        let get_allowed_target_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "get_allowed_target",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_network" => U256::from(1i64),
+            },
+        )
+        .build();
+
+        builder
+            .exec(get_allowed_target_request)
+            .expect_success()
+            .commit();
+
+        let get_token_name_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "get_token_name",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(get_token_name_request)
+            .expect_success()
+            .commit();
+
+        let list_target_networks_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "list_target_networks",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(list_target_networks_request)
+            .expect_success()
+            .commit();
+
+        let update_target_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "update_target",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_network" => U256::from(1i64),
+                "target_token" => "asd".to_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_target_request)
+            .expect_success()
+            .commit();
+
+        let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "swap",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_token" => "qwe".to_string(),
+                "target_address" => "qwe_addr".to_string(),
+                "target_network" => U256::from(1i64),
+                "amount" => U256::from(1i64),
+            },
+        )
+        .build();
+
+        builder.exec(swap_request).expect_failure().commit();
+
+        let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "swap",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_token" => "asd".to_string(),
+                "target_address" => "qwe_addr".to_string(),
+                "target_network" => U256::from(1i64),
+                "amount" => U256::from(1i64),
+            },
+        )
+        .build();
+
+        builder.exec(swap_request).expect_success().commit();
+
+        let disallow_target_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "disallow_target",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_network" => U256::from(1i64),
+            },
+        )
+        .build();
+
+        builder
+            .exec(disallow_target_request)
+            .expect_success()
+            .commit();
+
+        let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "swap",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_token" => "asd".to_string(),
+                "target_address" => "qwe_addr".to_string(),
+                "target_network" => U256::from(1i64),
+                "amount" => U256::from(1i64),
+            },
+        )
+        .build();
+
+        builder.exec(swap_request).expect_failure().commit();
+
+        let get_allowed_target_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "get_allowed_target",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_network" => U256::from(1i64),
+            },
+        )
+        .build();
+
+        builder
+            .exec(get_allowed_target_request)
+            .expect_failure()
+            .commit();
+
+        // a removed target network can no longer be updated
+        let update_target_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "update_target",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_network" => U256::from(1i64),
+                "target_token" => "asd".to_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_target_request)
+            .expect_failure()
+            .commit();
+    }
+
+    #[test]
+    fn should_be_able_to_charge_and_withdraw_swap_fees() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let erc20_runtime_args = runtime_args! {
+            "name" => "FERRUM_ERC20".to_string(),
+            "symbol" => "F_ERC20".to_string(),
+            "chain_name" => CHAIN_NAME.to_string(),
+            "total_supply" => U256::from(500000i64),
+            "decimals" => 8u8,
+        };
+
+        let erc_20_install_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, ERC20_WASM, erc20_runtime_args)
+                .build();
+
+        builder
+            .exec(erc_20_install_request)
+            .expect_success()
+            .commit();
+
+        let erc20_contract_hash = get_erc20_contract_hash(&builder);
+
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract_hash,
+            "mint",
+            runtime_args! {},
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+
+        let contract_installation_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            BRIDGE_POOL_WASM,
+            runtime_args! {
+                "chain_name" => CHAIN_NAME.to_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(contract_installation_request)
+            .expect_success()
+            .commit();
+
+        let bridge_pool_contract_package_hash = get_bridge_pool_contract_package_hash(&builder);
+
+        let bridge_pool_contract_hash = get_bridge_pool_contract_hash(&builder);
+
+        let bridge_pool_contract_key: Key = bridge_pool_contract_package_hash.into();
+
+        let approve_args = runtime_args! {
+            "spender" => bridge_pool_contract_key,
+            "amount" => U256::from(1000i64),
+        };
+
+        let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract_hash,
+            "approve",
+            approve_args,
+        )
+        .build();
+
+        builder.exec(approve_request).expect_success().commit();
+
+        let erc20_contract_package_hash = get_erc20_contract_package_hash(&builder);
+
+        let allow_target_args = runtime_args! {
+            "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            "token_name" => "some_unusual_token_name".to_string() ,
+            "target_token" => "qwe".to_string() ,
+            "target_network" => U256::from(1i64),
+        };
+
+        let allow_target_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "allow_target",
+            allow_target_args,
+        )
+        .build();
+
+        builder.exec(allow_target_request).expect_success().commit();
+
+        // fees above 10% are rejected
+        let set_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "set_fee",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "fee_bps" => 1001u32,
+            },
+        )
+        .build();
+
+        builder.exec(set_fee_request).expect_failure().commit();
+
+        let set_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "set_fee",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "fee_bps" => 100u32,
+            },
+        )
+        .build();
+
+        builder.exec(set_fee_request).expect_success().commit();
+
+        // the target network fee overrides the token fee
+        let set_target_network_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "set_target_network_fee",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_network" => U256::from(1i64),
+                "fee_bps" => 200u32,
+            },
+        )
+        .build();
+
+        builder
+            .exec(set_target_network_fee_request)
+            .expect_success()
+            .commit();
+
+        let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "swap",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_token" => "qwe".to_string(),
+                "target_address" => "qwe_addr".to_string(),
+                "target_network" => U256::from(1i64),
+                "amount" => U256::from(1000i64),
+            },
+        )
+        .build();
+
+        builder.exec(swap_request).expect_success().commit();
+
+        // there is nobody to pay the fees to yet
+        let withdraw_fees_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "withdraw_fees",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(withdraw_fees_request)
+            .expect_failure()
+            .commit();
+
+        let fee_recipient = AccountHash::new([7u8; 32]);
+
+        let set_fee_recipient_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "set_fee_recipient",
+            runtime_args! {
+                "fee_recipient" => Key::Account(fee_recipient),
+            },
+        )
+        .build();
+
+        builder
+            .exec(set_fee_recipient_request)
+            .expect_success()
+            .commit();
+
+        let withdraw_fees_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "withdraw_fees",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(withdraw_fees_request)
+            .expect_success()
+            .commit();
+
+        let erc20_contract_key: Key = erc20_contract_hash.into();
+
+        let balance = balance_dictionary(&builder, erc20_contract_key, Key::Account(fee_recipient));
+        assert_eq!(balance, U256::from(20u64));
+    }
+
+    #[test]
+    fn should_only_be_able_to_swap_amounts_within_the_swap_limits() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let erc20_runtime_args = runtime_args! {
+            "name" => "FERRUM_ERC20".to_string(),
+            "symbol" => "F_ERC20".to_string(),
+            "chain_name" => CHAIN_NAME.to_string(),
+            "total_supply" => U256::from(500000i64),
+            "decimals" => 8u8,
+        };
+
+        let erc_20_install_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, ERC20_WASM, erc20_runtime_args)
+                .build();
+
+        builder
+            .exec(erc_20_install_request)
+            .expect_success()
+            .commit();
+
+        let erc20_contract_hash = get_erc20_contract_hash(&builder);
+
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract_hash,
+            "mint",
+            runtime_args! {},
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+
+        let contract_installation_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            BRIDGE_POOL_WASM,
+            runtime_args! {
+                "chain_name" => CHAIN_NAME.to_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(contract_installation_request)
+            .expect_success()
+            .commit();
+
+        let bridge_pool_contract_package_hash = get_bridge_pool_contract_package_hash(&builder);
+
+        let bridge_pool_contract_hash = get_bridge_pool_contract_hash(&builder);
+
+        let bridge_pool_contract_key: Key = bridge_pool_contract_package_hash.into();
+
+        let approve_args = runtime_args! {
+            "spender" => bridge_pool_contract_key,
+            "amount" => U256::from(10i64),
+        };
+
+        let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract_hash,
+            "approve",
+            approve_args,
+        )
+        .build();
+
+        builder.exec(approve_request).expect_success().commit();
+
+        let erc20_contract_package_hash = get_erc20_contract_package_hash(&builder);
+
+        let allow_target_args = runtime_args! {
+            "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            "token_name" => "some_unusual_token_name".to_string() ,
+            "target_token" => "qwe".to_string() ,
+            "target_network" => U256::from(1i64),
+        };
+
+        let allow_target_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "allow_target",
+            allow_target_args,
+        )
+        .build();
+
+        builder.exec(allow_target_request).expect_success().commit();
+
+        // the minimum can not be above the maximum
+        let set_swap_limits_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "set_swap_limits",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_network" => U256::from(1i64),
+                "min_amount" => U256::from(5i64),
+                "max_amount" => U256::from(2i64),
+            },
+        )
+        .build();
+
+        builder
+            .exec(set_swap_limits_request)
+            .expect_failure()
+            .commit();
+
+        let set_swap_limits_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "set_swap_limits",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_network" => U256::from(1i64),
+                "min_amount" => U256::from(2i64),
+                "max_amount" => U256::from(5i64),
+            },
+        )
+        .build();
+
+        builder
+            .exec(set_swap_limits_request)
+            .expect_success()
+            .commit();
+
+        let get_swap_limits_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "get_swap_limits",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_network" => U256::from(1i64),
+            },
+        )
+        .build();
+
+        builder
+            .exec(get_swap_limits_request)
+            .expect_success()
+            .commit();
+
+        let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "swap",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_token" => "qwe".to_string(),
+                "target_address" => "qwe_addr".to_string(),
+                "target_network" => U256::from(1i64),
+                "amount" => U256::from(1i64),
+            },
+        )
+        .build();
+
+        builder.exec(swap_request).expect_failure().commit();
+
+        let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "swap",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_token" => "qwe".to_string(),
+                "target_address" => "qwe_addr".to_string(),
+                "target_network" => U256::from(1i64),
+                "amount" => U256::from(6i64),
+            },
+        )
+        .build();
+
+        builder.exec(swap_request).expect_failure().commit();
+
+        let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "swap",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_token" => "qwe".to_string(),
+                "target_address" => "qwe_addr".to_string(),
+                "target_network" => U256::from(1i64),
+                "amount" => U256::from(3i64),
+            },
+        )
+        .build();
+
+        builder.exec(swap_request).expect_success().commit();
+    }
+
+    #[test]
+    fn should_time_lock_large_withdrawals_until_claimed() {
+        let mut pool = install_pool_with_liquidity(U256::from(9i64));
+        let erc20_contract_key: Key = pool.erc20_contract_hash.into();
+
+        let expiry = 1_000_000u64;
+        let amount = U256::from(2i64);
+        let (message_hash, withdraw_signed_args, signer) = sign_withdrawal(
+            &pool,
+            amount,
+            "6b166cc8016d4ddb7a2578245ac9de73bd95f30ea960ab53dec02141623832dd",
+            expiry,
+        );
+        add_signer(&mut pool, signer);
+
+        let set_withdrawal_delay_args = |token_address: String, threshold: U256, delay: u64| {
+            runtime_args! {
+                "token_address" => token_address,
+                "threshold" => threshold,
+                "delay" => delay,
+            }
+        };
+        let token_address = pool.erc20_contract_package_hash.to_formatted_string();
+
+        // only registered tokens can be time locked
+        call_bridge_pool(
+            &mut pool,
+            "set_withdrawal_delay",
+            set_withdrawal_delay_args(token_address.clone(), U256::one(), 1000),
+        )
+        .expect_failure()
+        .commit();
+
+        allow_target(&mut pool);
+
+        // neither the threshold nor the delay can be zero
+        for (threshold, delay) in [(U256::zero(), 1000), (U256::one(), 0)] {
+            call_bridge_pool(
+                &mut pool,
+                "set_withdrawal_delay",
+                set_withdrawal_delay_args(token_address.clone(), threshold, delay),
+            )
+            .expect_failure()
+            .commit();
+        }
+
+        call_bridge_pool(
+            &mut pool,
+            "set_withdrawal_delay",
+            set_withdrawal_delay_args(token_address, U256::one(), 1000),
+        )
+        .expect_success()
+        .commit();
+
+        // the withdrawal is above the threshold so it is only queued
+        let withdraw_signed_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            pool.bridge_pool_contract_hash,
+            "withdraw_signed",
+            withdraw_signed_args,
+        )
+        .with_block_time(expiry)
+        .build();
+
+        pool.builder
+            .exec(withdraw_signed_request)
+            .expect_success()
+            .commit();
+
+        let balance_after_queue = balance_dictionary(
+            &pool.builder,
+            erc20_contract_key,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        );
+
+        let early_claim_withdrawal_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            pool.bridge_pool_contract_hash,
+            "claim_withdrawal",
+            runtime_args! {
+                "message_hash" => message_hash.clone(),
+            },
+        )
+        .with_block_time(expiry + 999)
+        .build();
+
+        pool.builder
+            .exec(early_claim_withdrawal_request)
+            .expect_failure()
+            .commit();
+
+        let claim_withdrawal_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            pool.bridge_pool_contract_hash,
+            "claim_withdrawal",
+            runtime_args! {
+                "message_hash" => message_hash.clone(),
+            },
+        )
+        .with_block_time(expiry + 1000)
+        .build();
+
+        pool.builder
+            .exec(claim_withdrawal_request)
+            .expect_success()
+            .commit();
+
+        let balance = balance_dictionary(
+            &pool.builder,
+            erc20_contract_key,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        );
+        assert_eq!(balance, balance_after_queue + amount);
+
+        // a claimed withdrawal can no longer be vetoed
+        let veto_withdrawal_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            pool.bridge_pool_contract_hash,
+            "veto_withdrawal",
+            runtime_args! {
+                "message_hash" => message_hash,
+            },
+        )
+        .build();
+
+        pool.builder
+            .exec(veto_withdrawal_request)
+            .expect_failure()
+            .commit();
+    }
+
+    #[test]
+    fn should_mint_and_burn_lp_tokens_for_liquidity() {
+        let mut pool = install_pool();
+        approve_pool(&mut pool, U256::from(10i64));
+        allow_target(&mut pool);
+        let lp_token_contract_hash = install_lp_token(&mut pool);
+        let lp_token_contract_key: Key = lp_token_contract_hash.into();
+
+        add_liquidity(&mut pool, U256::from(9i64));
+
+        let lp_balance = balance_dictionary(
+            &pool.builder,
+            lp_token_contract_key,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        );
+        assert_eq!(lp_balance, U256::from(9u64));
+
+        // LP tokens can be transferred like any other token
+        call_contract(
+            &mut pool,
+            lp_token_contract_hash,
+            "transfer",
+            runtime_args! {
+                "recipient" => Key::Account(AccountHash::new([7u8; 32])),
+                "amount" => U256::from(4i64),
+            },
+        )
+        .expect_success()
+        .commit();
+
+        // only the bridge pool can mint LP tokens
+        call_contract(
+            &mut pool,
+            lp_token_contract_hash,
+            "mint",
+            runtime_args! {
+                "owner" => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                "amount" => U256::from(4i64),
+            },
+        )
+        .expect_failure()
+        .commit();
+
+        let args = remove_liquidity_args(&pool, U256::from(5i64));
+        call_bridge_pool(&mut pool, "remove_liquidity", args)
+            .expect_success()
+            .commit();
+
+        let lp_balance = balance_dictionary(
+            &pool.builder,
+            lp_token_contract_key,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        );
+        assert_eq!(lp_balance, U256::zero());
+
+        // the transferred LP tokens can not be redeemed by the sender anymore
+        let args = remove_liquidity_args(&pool, U256::one());
+        call_bridge_pool(&mut pool, "remove_liquidity", args)
+            .expect_failure()
+            .commit();
+    }
+
+    #[test]
+    fn should_pay_swap_fee_rewards_to_liquidity_providers() {
+        let mut pool = install_pool();
+        approve_pool(&mut pool, U256::from(1000i64));
+        allow_target(&mut pool);
+        add_liquidity(&mut pool, U256::from(100i64));
+        let erc20_contract_key: Key = pool.erc20_contract_hash.into();
+        let token_address = pool.erc20_contract_package_hash.to_formatted_string();
+
+        // nothing was earned yet
+        let args = token_address_args(&pool);
+        call_bridge_pool(&mut pool, "claim_rewards", args)
+            .expect_failure()
+            .commit();
+
+        let set_reward_share_args = |reward_share_bps: u32| {
+            runtime_args! {
+                "token_address" => token_address.clone(),
+                "reward_share_bps" => reward_share_bps,
+            }
+        };
+
+        // liquidity providers can not get more than the whole fee
+        call_bridge_pool(&mut pool, "set_reward_share", set_reward_share_args(10001))
+            .expect_failure()
+            .commit();
+
+        call_bridge_pool(&mut pool, "set_reward_share", set_reward_share_args(5000))
+            .expect_success()
+            .commit();
+
+        call_bridge_pool(
+            &mut pool,
+            "set_fee",
+            runtime_args! {
+                "token_address" => token_address.clone(),
+                "fee_bps" => 1000u32,
+            },
+        )
+        .expect_success()
+        .commit();
+
+        let args = swap_args(&pool, U256::from(200i64));
+        call_bridge_pool(&mut pool, "swap", args)
+            .expect_success()
+            .commit();
+
+        let args = token_address_args(&pool);
+        call_bridge_pool(&mut pool, "get_pending_rewards", args)
+            .expect_success()
+            .commit();
+
+        let balance_before_claim = balance_dictionary(
+            &pool.builder,
+            erc20_contract_key,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        );
+
+        // half of the fee of 20 goes to the only liquidity provider
+        let args = token_address_args(&pool);
+        call_bridge_pool(&mut pool, "claim_rewards", args)
+            .expect_success()
+            .commit();
+
+        let balance = balance_dictionary(
+            &pool.builder,
+            erc20_contract_key,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        );
+        assert_eq!(balance, balance_before_claim + U256::from(10u64));
+
+        let args = token_address_args(&pool);
+        call_bridge_pool(&mut pool, "claim_rewards", args)
+            .expect_failure()
+            .commit();
+
+        let fee_recipient = AccountHash::new([7u8; 32]);
+
+        call_bridge_pool(
+            &mut pool,
+            "set_fee_recipient",
+            runtime_args! {
+                "fee_recipient" => Key::Account(fee_recipient),
+            },
+        )
+        .expect_success()
+        .commit();
+
+        // the rest of the fee is left for the fee recipient
+        let args = token_address_args(&pool);
+        call_bridge_pool(&mut pool, "withdraw_fees", args)
+            .expect_success()
+            .commit();
+
+        let balance = balance_dictionary(
+            &pool.builder,
+            erc20_contract_key,
+            Key::Account(fee_recipient),
+        );
+        assert_eq!(balance, U256::from(10u64));
+    }
+
+    #[test]
+    fn should_move_reward_shares_with_lp_token_transfers() {
+        let mut pool = install_pool();
+        approve_pool(&mut pool, U256::from(1000i64));
+        allow_target(&mut pool);
+        let lp_token_contract_hash = install_lp_token(&mut pool);
+        let token_address = pool.erc20_contract_package_hash.to_formatted_string();
+
+        for (entry_point, args) in [
+            (
+                "set_reward_share",
+                runtime_args! {
+                    "token_address" => token_address.clone(),
+                    "reward_share_bps" => 5000u32,
+                },
+            ),
+            (
+                "set_fee",
+                runtime_args! {
+                    "token_address" => token_address.clone(),
+                    "fee_bps" => 1000u32,
+                },
+            ),
+        ] {
+            call_bridge_pool(&mut pool, entry_point, args)
+                .expect_success()
+                .commit();
+        }
+        add_liquidity(&mut pool, U256::from(100i64));
+
+        // half of the fee of 20 is earned on the 100 shares of the only provider
+        let args = swap_args(&pool, U256::from(200i64));
+        call_bridge_pool(&mut pool, "swap", args)
+            .expect_success()
+            .commit();
+
+        let provider = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+        let lp_recipient = Key::Account(AccountHash::new([7u8; 32]));
+        call_contract(
+            &mut pool,
+            lp_token_contract_hash,
+            "transfer",
+            runtime_args! {
+                "recipient" => lp_recipient,
+                "amount" => U256::from(60i64),
+            },
+        )
+        .expect_success()
+        .commit();
+
+        // the shares of the transferred LP tokens moved to the recipient, the rewards earned
+        // before the transfer stayed with the sender
+        let (provider_shares, _, provider_rewards) = provider_rewards_dictionary(&pool, provider);
+        assert_eq!(provider_shares, U256::from(40u64));
+        assert_eq!(provider_rewards, U256::from(10u64));
+        let (recipient_shares, _, recipient_rewards) =
+            provider_rewards_dictionary(&pool, lp_recipient);
+        assert_eq!(recipient_shares, U256::from(60u64));
+        assert_eq!(recipient_rewards, U256::zero());
+        assert_eq!(total_reward_shares(&pool), U256::from(100u64));
+
+        // adding liquidity after the transfer only adds the new liquidity to the shares
+        approve_pool(&mut pool, U256::from(1i64));
+        add_liquidity(&mut pool, U256::from(1i64));
+        let (provider_shares, _, _) = provider_rewards_dictionary(&pool, provider);
+        assert_eq!(provider_shares, U256::from(41u64));
+        assert_eq!(total_reward_shares(&pool), U256::from(101u64));
+
+        // shares can only be moved by the LP token
+        call_bridge_pool(
+            &mut pool,
+            "transfer_reward_shares",
+            runtime_args! {
+                "owner" => lp_recipient,
+                "recipient" => provider,
+                "amount" => U256::from(60i64),
+            },
+        )
+        .expect_failure()
+        .commit();
+        assert_eq!(total_reward_shares(&pool), U256::from(101u64));
+
+        let erc20_contract_key: Key = pool.erc20_contract_hash.into();
+        let balance_before_claim = balance_dictionary(&pool.builder, erc20_contract_key, provider);
+
+        let args = token_address_args(&pool);
+        call_bridge_pool(&mut pool, "claim_rewards", args)
+            .expect_success()
+            .commit();
+
+        assert_eq!(
+            balance_dictionary(&pool.builder, erc20_contract_key, provider),
+            balance_before_claim + U256::from(10u64)
+        );
+    }
+
+    #[test]
+    fn should_track_the_total_liquidity_of_a_token() {
+        let mut pool = install_pool();
+        approve_pool(&mut pool, U256::from(1000i64));
+        allow_target(&mut pool);
+        add_liquidity(&mut pool, U256::from(100i64));
+
+        let args = runtime_args! {
+            "token_address" => pool.erc20_contract_package_hash.to_formatted_string(),
+            "fee_bps" => 1000u32,
+        };
+        call_bridge_pool(&mut pool, "set_fee", args)
+            .expect_success()
+            .commit();
+
+        // the fee of 20 is not part of the liquidity
+        let args = swap_args(&pool, U256::from(200i64));
+        call_bridge_pool(&mut pool, "swap", args)
+            .expect_success()
+            .commit();
+
+        let args = remove_liquidity_args(&pool, U256::from(30i64));
+        call_bridge_pool(&mut pool, "remove_liquidity", args)
+            .expect_success()
+            .commit();
+
+        for entry_point in ["get_total_liquidity", "get_pool_balance"] {
+            let args = token_address_args(&pool);
+            call_bridge_pool(&mut pool, entry_point, args)
+                .expect_success()
+                .commit();
+        }
+
+        let total_liquidity = total_liquidity_dictionary(
+            &pool.builder,
+            pool.bridge_pool_contract_hash,
+            pool.erc20_contract_package_hash,
+        );
+        assert_eq!(total_liquidity, U256::from(250u64));
+
+        let pool_balance = balance_dictionary(
+            &pool.builder,
+            pool.erc20_contract_hash.into(),
+            pool.bridge_pool_contract_package_hash.into(),
+        );
+        assert_eq!(pool_balance, total_liquidity + U256::from(20u64));
+    }
+
+    #[test]
+    fn should_be_able_to_add_liquidity_and_withdraw_signed_from_a_contract() {
+        let mut pool = install_pool();
+        let bridge_pool_contract_key: Key = pool.bridge_pool_contract_package_hash.into();
+        let erc20_contract_hash = pool.erc20_contract_hash;
+
+        let bridge_caller_installation_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            BRIDGE_CALLER_WASM,
+            runtime_args! {},
+        )
+        .build();
+
+        pool.builder
+            .exec(bridge_caller_installation_request)
+            .expect_success()
+            .commit();
+
+        let bridge_caller_contract_package_hash =
+            get_bridge_caller_contract_package_hash(&pool.builder);
+        let bridge_caller_contract_hash = get_bridge_caller_contract_hash(&pool.builder);
+        let bridge_caller_contract_key: Key = bridge_caller_contract_package_hash.into();
+
+        // the liquidity is provided by the bridge caller contract
+        call_contract(
+            &mut pool,
+            erc20_contract_hash,
+            "transfer",
+            runtime_args! {
+                "recipient" => bridge_caller_contract_key,
+                "amount" => U256::from(10i64),
+            },
+        )
+        .expect_success()
+        .commit();
+
+        let args = runtime_args! {
+            "token" => Key::from(pool.erc20_contract_package_hash),
+            "spender" => bridge_pool_contract_key,
+            "amount" => U256::from(10i64),
+        };
+        call_contract(&mut pool, bridge_caller_contract_hash, "approve", args)
+            .expect_success()
+            .commit();
+
+        let args = runtime_args! {
+            "bridge_pool" => bridge_pool_contract_key,
+            "amount" => U256::from(9i64),
+            "token_address" => pool.erc20_contract_package_hash.to_formatted_string(),
+        };
+        call_contract(
+            &mut pool,
+            bridge_caller_contract_hash,
+            "add_liquidity",
+            args,
+        )
+        .expect_success()
+        .commit();
+
+        // a contract package is identified by the hex of its hash
+        let (_, withdraw_signed_args, signer) = sign_withdrawal_for(
+            &pool,
+            pool.erc20_contract_package_hash,
+            bridge_caller_contract_package_hash.to_string(),
+            U256::one(),
+            "6b166cc8016d4ddb7a2578245ac9de73bd95f30ea960ab53dec02141623832dd",
+            1_000_000,
+        );
+        add_signer(&mut pool, signer);
+
+        let mut bridge_caller_withdraw_signed_args = withdraw_signed_args.clone();
+        bridge_caller_withdraw_signed_args
+            .insert("bridge_pool", bridge_pool_contract_key)
+            .unwrap();
+
+        // the withdrawal was authorized for the contract, not for the account
+        call_bridge_pool(&mut pool, "withdraw_signed", withdraw_signed_args)
+            .expect_failure()
+            .commit();
+
+        call_contract(
+            &mut pool,
+            bridge_caller_contract_hash,
+            "withdraw_signed",
+            bridge_caller_withdraw_signed_args.clone(),
+        )
+        .expect_success()
+        .commit();
+
+        let balance = balance_dictionary(
+            &pool.builder,
+            erc20_contract_hash.into(),
+            bridge_caller_contract_key,
+        );
+        assert_eq!(balance, U256::from(2u64));
+
+        // the authorization can not be used twice
+        call_contract(
+            &mut pool,
+            bridge_caller_contract_hash,
+            "withdraw_signed",
+            bridge_caller_withdraw_signed_args,
+        )
+        .expect_failure()
+        .commit();
+    }
+
+    #[test]
+    fn should_be_able_to_bridge_native_cspr() {
+        let mut pool = install_pool();
+        let bridge_pool_contract_key: Key = pool.bridge_pool_contract_package_hash.into();
+
+        // native CSPR is bridged as the token with the zero contract package hash
+        let native_token = ContractPackageHash::new([0u8; 32]);
+        let native_token_string = native_token.to_formatted_string();
+
+        call_bridge_pool(
+            &mut pool,
+            "allow_target",
+            runtime_args! {
+                "token_address" => native_token_string.clone(),
+                "token_name" => "CSPR".to_string(),
+                "target_token" => "wcspr".to_string(),
+                "target_network" => U256::from(1i64),
+            },
+        )
+        .expect_success()
+        .commit();
+
+        let native_call_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            NATIVE_CALL_WASM,
+            runtime_args! {
+                "bridge_pool_contract_package_hash" => bridge_pool_contract_key,
+                "entry_point" => "add_liquidity_native".to_string(),
+                "amount" => U256::from(10_000_000_000u64),
+            },
+        )
+        .build();
+
+        pool.builder
+            .exec(native_call_request)
+            .expect_success()
+            .commit();
+
+        let pool_purse = get_pool_purse(&pool.builder, pool.bridge_pool_contract_hash);
+        assert_eq!(
+            pool.builder.get_purse_balance(pool_purse),
+            U512::from(10_000_000_000u64)
+        );
+
+        // native CSPR can only be added from a purse
+        let args = runtime_args! {
+            "amount" => U256::from(1i64),
+            "token_address" => native_token_string.clone(),
+            "bridge_pool_contract_package_hash" => pool.bridge_pool_contract_package_hash.to_formatted_string(),
+        };
+        call_bridge_pool(&mut pool, "add_liquidity", args)
+            .expect_failure()
+            .commit();
+
+        let native_call_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            NATIVE_CALL_WASM,
+            runtime_args! {
+                "bridge_pool_contract_package_hash" => bridge_pool_contract_key,
+                "entry_point" => "swap_native".to_string(),
+                "amount" => U256::from(5_000_000_000u64),
+                "target_network" => U256::from(1i64),
+                "target_token" => "wcspr".to_string(),
+                "target_address" => "qwe_addr".to_string(),
+            },
+        )
+        .build();
+
+        pool.builder
+            .exec(native_call_request)
+            .expect_success()
+            .commit();
+
+        assert_eq!(
+            pool.builder.get_purse_balance(pool_purse),
+            U512::from(15_000_000_000u64)
+        );
+
+        call_bridge_pool(
+            &mut pool,
+            "get_pool_balance",
+            runtime_args! {
+                "token_address" => native_token_string,
+            },
+        )
+        .expect_success()
+        .commit();
+
+        let (_, withdraw_signed_args, signer) = sign_withdrawal_for(
+            &pool,
+            native_token,
+            (*DEFAULT_ACCOUNT_ADDR).to_string(),
+            U256::from(3_000_000_000u64),
+            "6b166cc8016d4ddb7a2578245ac9de73bd95f30ea960ab53dec02141623832dd",
+            1_000_000,
+        );
+        add_signer(&mut pool, signer);
+
+        // withdraw_signed_native takes the same arguments less the token address
+        let withdraw_signed_native_args: RuntimeArgs = withdraw_signed_args
+            .named_args()
+            .filter(|arg| arg.name() != "token_address")
+            .cloned()
+            .collect::<Vec<_>>()
+            .into();
+
+        call_bridge_pool(
+            &mut pool,
+            "withdraw_signed_native",
+            withdraw_signed_native_args,
+        )
+        .expect_success()
+        .commit();
+
+        assert_eq!(
+            pool.builder.get_purse_balance(pool_purse),
+            U512::from(12_000_000_000u64)
+        );
+    }
+
+    #[test]
+    fn should_be_able_to_add_and_remove_liquidity_of_a_cep18_token() {
+        let mut pool = install_pool();
+
+        let cep18_token_installation_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CEP18_TOKEN_WASM,
+            runtime_args! {
+                "name" => "FERRUM_CEP18".to_string(),
+                "symbol" => "F_CEP18".to_string(),
+                "chain_name" => CHAIN_NAME.to_string(),
+                "total_supply" => U256::from(500000i64),
+                "decimals" => 8u8,
+            },
+        )
+        .build();
+
+        pool.builder
+            .exec(cep18_token_installation_request)
+            .expect_success()
+            .commit();
+
+        // the pool helpers work on the CEP-18 token from here on
+        pool.erc20_contract_hash = get_cep18_token_contract_hash(&pool.builder);
+        pool.erc20_contract_package_hash = get_cep18_token_contract_package_hash(&pool.builder);
+        let cep18_token_contract_key: Key = pool.erc20_contract_hash.into();
+        let bridge_pool_contract_key: Key = pool.bridge_pool_contract_package_hash.into();
+
+        approve_pool(&mut pool, U256::from(10i64));
+        allow_target(&mut pool);
+
+        let add_liquidity_args = runtime_args! {
+            "amount" => U256::from(9i64),
+            "token_address" => pool.erc20_contract_package_hash.to_formatted_string(),
+            "bridge_pool_contract_package_hash" => pool.bridge_pool_contract_package_hash.to_formatted_string(),
+        };
+
+        // called through the erc20 layout, the flag returned by transfer_from can not be read
+        call_bridge_pool(&mut pool, "add_liquidity", add_liquidity_args)
+            .expect_failure()
+            .commit();
+
+        let set_token_standard_args = |pool: &TestPool, token_standard: &str| {
+            runtime_args! {
+                "token_address" => pool.erc20_contract_package_hash.to_formatted_string(),
+                "token_standard" => token_standard.to_string(),
+            }
+        };
+
+        let args = set_token_standard_args(&pool, "unknown");
+        call_bridge_pool(&mut pool, "set_token_standard", args)
+            .expect_failure()
+            .commit();
+
+        let args = set_token_standard_args(&pool, "cep18");
+        call_bridge_pool(&mut pool, "set_token_standard", args)
+            .expect_success()
+            .commit();
+
+        add_liquidity(&mut pool, U256::from(9i64));
+
+        let pool_balance = balance_dictionary(
+            &pool.builder,
+            cep18_token_contract_key,
+            bridge_pool_contract_key,
+        );
+        assert_eq!(pool_balance, U256::from(9u64));
+
+        let args = remove_liquidity_args(&pool, U256::from(5i64));
+        call_bridge_pool(&mut pool, "remove_liquidity", args)
+            .expect_success()
+            .commit();
+
+        let pool_balance = balance_dictionary(
+            &pool.builder,
+            cep18_token_contract_key,
+            bridge_pool_contract_key,
+        );
+        assert_eq!(pool_balance, U256::from(4u64));
+
+        let balance = balance_dictionary(
+            &pool.builder,
+            cep18_token_contract_key,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        );
+        assert_eq!(balance, U256::from(499996u64));
+
+        // the token recorded an event for both transfers
+        let events_length: u32 = pool
+            .builder
+            .query(
+                None,
+                cep18_token_contract_key,
+                &["__events_length".to_string()],
+            )
+            .expect("must have __events_length entry")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t()
+            .expect("must convert to u32");
+        assert_eq!(events_length, 2);
+
+        // the token standard can not be changed while the pool holds the token
+        let args = set_token_standard_args(&pool, "erc20");
+        call_bridge_pool(&mut pool, "set_token_standard", args)
+            .expect_failure()
+            .commit();
+    }
+
+    #[test]
+    fn should_credit_the_balance_delta_of_a_fee_on_transfer_token() {
+        let mut pool = install_pool();
+
+        // the token burns 1% of every transfer from the recipient
+        let fee_token_installation_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            FEE_TOKEN_WASM,
+            runtime_args! {
+                "name" => "FERRUM_FEE".to_string(),
+                "symbol" => "F_FEE".to_string(),
+                "chain_name" => CHAIN_NAME.to_string(),
+                "total_supply" => U256::from(500000i64),
+                "decimals" => 8u8,
+                "transfer_fee" => 100u32,
+            },
+        )
+        .build();
+
+        pool.builder
+            .exec(fee_token_installation_request)
+            .expect_success()
+            .commit();
+
+        // the pool helpers work on the fee token from here on
+        pool.erc20_contract_hash = get_fee_token_contract_hash(&pool.builder);
+        pool.erc20_contract_package_hash = get_fee_token_contract_package_hash(&pool.builder);
+        let fee_token_contract_key: Key = pool.erc20_contract_hash.into();
+        let bridge_pool_contract_key: Key = pool.bridge_pool_contract_package_hash.into();
+
+        approve_pool(&mut pool, U256::from(2000i64));
+        allow_target(&mut pool);
+
+        let args = runtime_args! {
+            "token_address" => pool.erc20_contract_package_hash.to_formatted_string(),
+            "balance_delta" => true,
+        };
+        call_bridge_pool(&mut pool, "set_balance_delta", args)
+            .expect_success()
+            .commit();
+
+        add_liquidity(&mut pool, U256::from(1000i64));
+
+        let total_liquidity = total_liquidity_dictionary(
+            &pool.builder,
+            pool.bridge_pool_contract_hash,
+            pool.erc20_contract_package_hash,
+        );
+        assert_eq!(total_liquidity, U256::from(990u64));
+
+        let args = swap_args(&pool, U256::from(1000i64));
+        call_bridge_pool(&mut pool, "swap", args)
+            .expect_success()
+            .commit();
+
+        let total_liquidity = total_liquidity_dictionary(
+            &pool.builder,
+            pool.bridge_pool_contract_hash,
+            pool.erc20_contract_package_hash,
+        );
+        assert_eq!(total_liquidity, U256::from(1980u64));
+
+        let pool_balance = balance_dictionary(
+            &pool.builder,
+            fee_token_contract_key,
+            bridge_pool_contract_key,
+        );
+        assert_eq!(pool_balance, total_liquidity);
+
+        // only the 990 the pool received were credited to the client
+        let args = remove_liquidity_args(&pool, U256::from(1000i64));
+        call_bridge_pool(&mut pool, "remove_liquidity", args)
+            .expect_failure()
+            .commit();
+
+        let args = remove_liquidity_args(&pool, U256::from(990i64));
+        call_bridge_pool(&mut pool, "remove_liquidity", args)
+            .expect_success()
+            .commit();
+
+        let pool_balance = balance_dictionary(
+            &pool.builder,
+            fee_token_contract_key,
+            bridge_pool_contract_key,
+        );
+        assert_eq!(pool_balance, U256::from(990u64));
+    }
+
+    #[test]
+    fn should_not_set_balance_delta_for_native_cspr() {
+        let mut pool = install_pool();
+
+        let native_token = ContractPackageHash::new([0u8; 32]).to_formatted_string();
+
+        call_bridge_pool(
+            &mut pool,
+            "allow_target",
+            runtime_args! {
+                "token_address" => native_token.clone(),
+                "token_name" => "CSPR".to_string(),
+                "target_token" => "qwe".to_string(),
+                "target_network" => U256::from(1i64),
+            },
+        )
+        .expect_success()
+        .commit();
+
+        call_bridge_pool(
+            &mut pool,
+            "set_balance_delta",
+            runtime_args! {
+                "token_address" => native_token,
+                "balance_delta" => true,
+            },
+        )
+        .expect_failure()
+        .commit();
+    }
+
+    #[test]
+    fn should_be_able_to_withdraw_signed_in_a_batch() {
+        let mut pool = install_pool_with_liquidity(U256::from(9i64));
+        let erc20_contract_key: Key = pool.erc20_contract_hash.into();
+        let bridge_pool_contract_key: Key = pool.bridge_pool_contract_package_hash.into();
+
+        let expiry = 1_000_000u64;
+        let (_, first_withdrawal_args, signer) = sign_withdrawal(
+            &pool,
+            U256::from(1i64),
+            "6b166cc8016d4ddb7a2578245ac9de73bd95f30ea960ab53dec02141623832dd",
+            expiry,
+        );
+        let (_, second_withdrawal_args, _) = sign_withdrawal(
+            &pool,
+            U256::from(2i64),
+            "7c277dd9127e5eec8b3689356bd0ef84ce06f41fb071bc64efd13252734943ee",
+            expiry,
+        );
+        let (_, large_withdrawal_args, _) = sign_withdrawal(
+            &pool,
+            U256::from(9i64),
+            "8d388eea238f6ffd9c479a467ce1f095df17f520c182cd75f0e24363845a54ff",
+            expiry,
+        );
+        add_signer(&mut pool, signer);
+
+        let first_withdrawal = batch_withdrawal(&first_withdrawal_args);
+        let second_withdrawal = batch_withdrawal(&second_withdrawal_args);
+        let large_withdrawal = batch_withdrawal(&large_withdrawal_args);
+
+        // the signature of the first withdrawal does not authorize another amount
+        let mut forged_withdrawal = first_withdrawal.clone();
+        forged_withdrawal.0 .2 = U256::from(5i64);
+
+        call_bridge_pool_at(
+            &mut pool,
+            "withdraw_signed_batch",
+            runtime_args! {
+                "withdrawals" => vec![first_withdrawal.clone(), forged_withdrawal.clone()],
+                "revert_on_failure" => true,
+            },
+            expiry,
+        )
+        .expect_failure()
+        .commit();
+
+        let pool_balance =
+            balance_dictionary(&pool.builder, erc20_contract_key, bridge_pool_contract_key);
+        assert_eq!(pool_balance, U256::from(9u64));
+
+        // failed withdrawals are skipped, the first withdrawal can only be paid out once and the
+        // large withdrawal exceeds the liquidity left after it
+        call_bridge_pool_at(
+            &mut pool,
+            "withdraw_signed_batch",
+            runtime_args! {
+                "withdrawals" => vec![
+                    first_withdrawal.clone(),
+                    large_withdrawal.clone(),
+                    forged_withdrawal,
+                    second_withdrawal,
+                    first_withdrawal,
+                ],
+                "revert_on_failure" => false,
+            },
+            expiry,
+        )
+        .expect_success()
+        .commit();
+
+        let pool_balance =
+            balance_dictionary(&pool.builder, erc20_contract_key, bridge_pool_contract_key);
+        assert_eq!(pool_balance, U256::from(6u64));
+
+        let total_liquidity = total_liquidity_dictionary(
+            &pool.builder,
+            pool.bridge_pool_contract_hash,
+            pool.erc20_contract_package_hash,
+        );
+        assert_eq!(total_liquidity, U256::from(6u64));
+
+        // the skipped large withdrawal did not use its message hash
+        approve_pool(&mut pool, U256::from(3i64));
+        add_liquidity(&mut pool, U256::from(3i64));
+
+        call_bridge_pool_at(
+            &mut pool,
+            "withdraw_signed_batch",
+            runtime_args! {
+                "withdrawals" => vec![large_withdrawal],
+                "revert_on_failure" => true,
+            },
+            expiry,
+        )
+        .expect_success()
+        .commit();
+
+        let pool_balance =
+            balance_dictionary(&pool.builder, erc20_contract_key, bridge_pool_contract_key);
+        assert_eq!(pool_balance, U256::zero());
+    }
+
+    #[test]
+    fn should_skip_time_locked_withdrawals_of_a_batch_without_using_their_message_hash() {
+        let mut pool = install_pool_with_liquidity(U256::from(9i64));
+        let erc20_contract_key: Key = pool.erc20_contract_hash.into();
+        let bridge_pool_contract_key: Key = pool.bridge_pool_contract_package_hash.into();
+
+        allow_target(&mut pool);
+        let args = runtime_args! {
+            "token_address" => pool.erc20_contract_package_hash.to_formatted_string(),
+            "threshold" => U256::one(),
+            "delay" => 1000u64,
+        };
+        call_bridge_pool(&mut pool, "set_withdrawal_delay", args)
+            .expect_success()
+            .commit();
+
+        let expiry = 1_000_000u64;
+        let (message_hash, withdraw_signed_args, signer) = sign_withdrawal(
+            &pool,
+            U256::from(10i64),
+            "6b166cc8016d4ddb7a2578245ac9de73bd95f30ea960ab53dec02141623832dd",
+            expiry,
+        );
+        add_signer(&mut pool, signer);
+        let withdrawal = batch_withdrawal(&withdraw_signed_args);
+
+        // the withdrawal would be time locked but exceeds the liquidity of the caller, so it is
+        // skipped before anything is written
+        call_bridge_pool_at(
+            &mut pool,
+            "withdraw_signed_batch",
+            runtime_args! {
+                "withdrawals" => vec![withdrawal.clone()],
+                "revert_on_failure" => false,
+            },
+            expiry,
+        )
+        .expect_success()
+        .commit();
+
+        let claim_withdrawal_args = runtime_args! {
+            "message_hash" => message_hash,
+        };
+        call_bridge_pool_at(
+            &mut pool,
+            "claim_withdrawal",
+            claim_withdrawal_args.clone(),
+            expiry + 1000,
+        )
+        .expect_failure()
+        .commit();
+
+        // its message hash is still unused, so it can be queued once the liquidity is there
+        approve_pool(&mut pool, U256::one());
+        add_liquidity(&mut pool, U256::one());
+
+        call_bridge_pool_at(
+            &mut pool,
+            "withdraw_signed_batch",
+            runtime_args! {
+                "withdrawals" => vec![withdrawal],
+                "revert_on_failure" => true,
+            },
+            expiry,
+        )
+        .expect_success()
+        .commit();
+
+        call_bridge_pool_at(
+            &mut pool,
+            "claim_withdrawal",
+            claim_withdrawal_args,
+            expiry + 1000,
+        )
+        .expect_success()
+        .commit();
+
+        let pool_balance =
+            balance_dictionary(&pool.builder, erc20_contract_key, bridge_pool_contract_key);
+        assert_eq!(pool_balance, U256::zero());
+    }
+
+    #[test]
+    fn should_pay_relayed_withdrawals_to_the_payee_and_the_fee_to_the_relayer() {
+        let mut pool = install_pool_with_liquidity(U256::from(9i64));
+        let erc20_contract_key: Key = pool.erc20_contract_hash.into();
+        let bridge_pool_contract_key: Key = pool.bridge_pool_contract_package_hash.into();
+
+        // the relayer has no liquidity in the pool, it only pays for the deploy
+        let relayer = AccountHash::new([9u8; 32]);
+        fund_account(&mut pool, relayer);
+
+        let expiry = 1_000_000u64;
+        let relayer_fee = U256::one();
+        let (_, withdraw_signed_relayed_args, signer) = sign_relayed_withdrawal(
+            &pool,
+            U256::from(5i64),
+            relayer_fee,
+            "6b166cc8016d4ddb7a2578245ac9de73bd95f30ea960ab53dec02141623832dd",
+            expiry,
+        );
+        add_signer(&mut pool, signer);
+
+        let payee_balance_before = balance_dictionary(
+            &pool.builder,
+            erc20_contract_key,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        );
+
+        // the relayer fee is covered by the signature
+        let mut raised_fee_args: RuntimeArgs = withdraw_signed_relayed_args
+            .named_args()
+            .filter(|arg| arg.name() != "relayer_fee")
+            .cloned()
+            .collect::<Vec<_>>()
+            .into();
+        raised_fee_args
+            .insert("relayer_fee", U256::from(2i64))
+            .unwrap();
+
+        call_bridge_pool_from(
+            &mut pool,
+            relayer,
+            "withdraw_signed_relayed",
+            raised_fee_args,
+            expiry,
+        )
+        .expect_failure()
+        .commit();
+
+        call_bridge_pool_from(
+            &mut pool,
+            relayer,
+            "withdraw_signed_relayed",
+            withdraw_signed_relayed_args.clone(),
+            expiry,
+        )
+        .expect_success()
+        .commit();
+
+        let relayer_balance =
+            balance_dictionary(&pool.builder, erc20_contract_key, Key::Account(relayer));
+        assert_eq!(relayer_balance, relayer_fee);
+
+        let payee_balance = balance_dictionary(
+            &pool.builder,
+            erc20_contract_key,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        );
+        assert_eq!(payee_balance, payee_balance_before + U256::from(4u64));
+
+        let pool_balance =
+            balance_dictionary(&pool.builder, erc20_contract_key, bridge_pool_contract_key);
+        assert_eq!(pool_balance, U256::from(4u64));
+
+        // the signed message can only be relayed once
+        call_bridge_pool_from(
+            &mut pool,
+            relayer,
+            "withdraw_signed_relayed",
+            withdraw_signed_relayed_args,
+            expiry,
+        )
+        .expect_failure()
+        .commit();
+    }
+
+    #[test]
+    fn should_queue_time_locked_relayed_withdrawals_for_any_account_to_claim() {
+        let mut pool = install_pool_with_liquidity(U256::from(9i64));
+        let erc20_contract_key: Key = pool.erc20_contract_hash.into();
+        let bridge_pool_contract_key: Key = pool.bridge_pool_contract_package_hash.into();
+
+        // neither the relayer nor the claimer have liquidity in the pool
+        let relayer = AccountHash::new([9u8; 32]);
+        let claimer = AccountHash::new([8u8; 32]);
+        fund_account(&mut pool, relayer);
+        fund_account(&mut pool, claimer);
+
+        allow_target(&mut pool);
+        let args = runtime_args! {
+            "token_address" => pool.erc20_contract_package_hash.to_formatted_string(),
+            "threshold" => U256::one(),
+            "delay" => 1000u64,
+        };
+        call_bridge_pool(&mut pool, "set_withdrawal_delay", args)
+            .expect_success()
+            .commit();
+
+        let expiry = 1_000_000u64;
+        let relayer_fee = U256::one();
+        let (message_hash, withdraw_signed_relayed_args, signer) = sign_relayed_withdrawal(
+            &pool,
+            U256::from(5i64),
+            relayer_fee,
+            "6b166cc8016d4ddb7a2578245ac9de73bd95f30ea960ab53dec02141623832dd",
+            expiry,
+        );
+        add_signer(&mut pool, signer);
+
+        let payee_balance_before = balance_dictionary(
+            &pool.builder,
+            erc20_contract_key,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        );
+
+        // the withdrawal is above the threshold so it is only queued for the payee
+        call_bridge_pool_from(
+            &mut pool,
+            relayer,
+            "withdraw_signed_relayed",
+            withdraw_signed_relayed_args,
+            expiry,
+        )
+        .expect_success()
+        .commit();
+
+        let pool_balance =
+            balance_dictionary(&pool.builder, erc20_contract_key, bridge_pool_contract_key);
+        assert_eq!(pool_balance, U256::from(9u64));
+
+        let claim_withdrawal_args = runtime_args! {
+            "message_hash" => message_hash,
+        };
+
+        call_bridge_pool_from(
+            &mut pool,
+            claimer,
+            "claim_withdrawal",
+            claim_withdrawal_args.clone(),
+            expiry + 999,
+        )
+        .expect_failure()
+        .commit();
+
+        // any account can claim it once the time lock has passed, and is paid the relayer fee
+        call_bridge_pool_from(
+            &mut pool,
+            claimer,
+            "claim_withdrawal",
+            claim_withdrawal_args.clone(),
+            expiry + 1000,
+        )
+        .expect_success()
+        .commit();
+
+        let claimer_balance =
+            balance_dictionary(&pool.builder, erc20_contract_key, Key::Account(claimer));
+        assert_eq!(claimer_balance, relayer_fee);
+
+        let payee_balance = balance_dictionary(
+            &pool.builder,
+            erc20_contract_key,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        );
+        assert_eq!(payee_balance, payee_balance_before + U256::from(4u64));
+
+        let pool_balance =
+            balance_dictionary(&pool.builder, erc20_contract_key, bridge_pool_contract_key);
+        assert_eq!(pool_balance, U256::from(4u64));
+
+        call_bridge_pool_from(
+            &mut pool,
+            claimer,
+            "claim_withdrawal",
+            claim_withdrawal_args,
+            expiry + 1000,
+        )
+        .expect_failure()
+        .commit();
+    }
+
+    #[test]
+    fn should_be_able_to_add_liquidity_and_swap_with_a_permit() {
+        let mut pool = install_pool();
+        let erc20_contract_hash = pool.erc20_contract_hash;
+        let erc20_contract_key: Key = erc20_contract_hash.into();
+        let bridge_pool_contract_key: Key = pool.bridge_pool_contract_package_hash.into();
+
+        // the owner account is the one of the secp256k1 key signing the permits
+        let private_key_bytes =
+            hex::decode("a7a08a23f69090a53a32814da1d262c8d2728d16bce420ae143978d85a06be49")
+                .unwrap();
+        let owner = AccountHash::from(&PublicKey::from(
+            &SecretKey::secp256k1_from_bytes(&private_key_bytes).unwrap(),
+        ));
+        let owner_key = Key::Account(owner);
+        fund_account(&mut pool, owner);
+
+        call_contract(
+            &mut pool,
+            erc20_contract_hash,
+            "transfer",
+            runtime_args! {
+                "recipient" => owner_key,
+                "amount" => U256::from(100i64),
+            },
+        )
+        .expect_success()
+        .commit();
+
+        let deadline = 1_000_000u64;
+        let erc20_contract_package_hash = pool.erc20_contract_package_hash;
+        let sign_permit = |amount: U256, nonce: U256| {
+            let message_hash = contract_utils::keccak::permit_message_hash(
+                contract_utils::keccak::permit_domain_separator(
+                    erc20_contract_package_hash.value(),
+                    "FERRUM_ERC20",
+                    PERMIT_VERSION,
+                    CHAIN_NAME,
+                ),
+                &owner_key.to_bytes().unwrap(),
+                &bridge_pool_contract_key.to_bytes().unwrap(),
+                amount,
+                nonce,
+                deadline,
+            );
+            hex::encode(contract_utils::keccak::ecdsa_sign(
+                &message_hash,
+                &private_key_bytes,
+            ))
+        };
+
+        let add_liquidity_with_permit_args = runtime_args! {
+            "amount" => U256::from(10i64),
+            "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            "deadline" => deadline,
+            "signature" => sign_permit(U256::from(10i64), U256::zero()),
+        };
+
+        // the permit can not be used after its deadline
+        call_bridge_pool_from(
+            &mut pool,
+            owner,
+            "add_liquidity_with_permit",
+            add_liquidity_with_permit_args.clone(),
+            deadline + 1,
+        )
+        .expect_failure()
+        .commit();
+
+        call_bridge_pool_from(
+            &mut pool,
+            owner,
+            "add_liquidity_with_permit",
+            add_liquidity_with_permit_args.clone(),
+            deadline,
+        )
+        .expect_success()
+        .commit();
+
+        let owner_balance = balance_dictionary(&pool.builder, erc20_contract_key, owner_key);
+        assert_eq!(owner_balance, U256::from(90i64));
+
+        let pool_balance =
+            balance_dictionary(&pool.builder, erc20_contract_key, bridge_pool_contract_key);
+        assert_eq!(pool_balance, U256::from(10i64));
+
+        let allowance = allowance_dictionary(
+            &pool.builder,
+            erc20_contract_key,
+            owner_key,
+            bridge_pool_contract_key,
+        );
+        assert_eq!(allowance, U256::zero());
+
+        // the nonce was used, so the same permit can not be replayed
+        call_bridge_pool_from(
+            &mut pool,
+            owner,
+            "add_liquidity_with_permit",
+            add_liquidity_with_permit_args,
+            deadline,
+        )
+        .expect_failure()
+        .commit();
+
+        allow_target(&mut pool);
+
+        // a permit submitted to the token by someone else first still lets the owner swap
+        let swap_signature = sign_permit(U256::from(5i64), U256::one());
+        let permit_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            pool.erc20_contract_hash,
+            "permit",
+            runtime_args! {
+                "owner" => owner_key,
+                "spender" => bridge_pool_contract_key,
+                "amount" => U256::from(5i64),
+                "deadline" => deadline,
+                "signature" => swap_signature.clone(),
+            },
+        )
+        .with_block_time(deadline)
+        .build();
+
+        pool.builder.exec(permit_request).expect_success().commit();
+
+        let mut swap_with_permit_args = swap_args(&pool, U256::from(5i64));
+        swap_with_permit_args.insert("deadline", deadline).unwrap();
+        swap_with_permit_args
+            .insert("signature", swap_signature)
+            .unwrap();
+
+        call_bridge_pool_from(
+            &mut pool,
+            owner,
+            "swap_with_permit",
+            swap_with_permit_args,
+            deadline,
+        )
+        .expect_success()
+        .commit();
+
+        let owner_balance = balance_dictionary(&pool.builder, erc20_contract_key, owner_key);
+        assert_eq!(owner_balance, U256::from(85i64));
+
+        let pool_balance =
+            balance_dictionary(&pool.builder, erc20_contract_key, bridge_pool_contract_key);
+        assert_eq!(pool_balance, U256::from(15i64));
+    }
+
+    #[test]
+    fn should_upgrade_a_pool_installed_with_string_keys() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let erc20_runtime_args = runtime_args! {
+            "name" => "FERRUM_ERC20".to_string(),
+            "symbol" => "F_ERC20".to_string(),
+            "chain_name" => CHAIN_NAME.to_string(),
+            "total_supply" => U256::from(500000i64),
+            "decimals" => 8u8,
+        };
+
+        let erc_20_install_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, ERC20_WASM, erc20_runtime_args)
+                .build();
+
+        builder
+            .exec(erc_20_install_request)
+            .expect_success()
+            .commit();
+
+        let erc20_contract_hash = get_erc20_contract_hash(&builder);
+
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract_hash,
+            "mint",
+            runtime_args! {},
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+
+        let legacy_pool_installation_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            LEGACY_POOL_WASM,
+            runtime_args! {},
+        )
+        .build();
+
+        builder
+            .exec(legacy_pool_installation_request)
+            .expect_success()
+            .commit();
+
+        let bridge_pool_contract_package_hash = get_bridge_pool_contract_package_hash(&builder);
+
+        let legacy_pool_contract_hash = get_bridge_pool_contract_hash(&builder);
+
+        let bridge_pool_contract_key: Key = bridge_pool_contract_package_hash.into();
+
+        let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract_hash,
+            "approve",
+            runtime_args! {
+                "spender" => bridge_pool_contract_key,
+                "amount" => U256::from(1000i64),
+            },
+        )
+        .build();
+
+        builder.exec(approve_request).expect_success().commit();
+
+        let erc20_contract_package_hash = get_erc20_contract_package_hash(&builder);
+
+        let allow_target_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            legacy_pool_contract_hash,
+            "allow_target",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "token_name" => "some_unusual_token_name".to_string(),
+                "target_token" => "qwe".to_string(),
+                "target_network" => U256::from(1i64),
+            },
+        )
+        .build();
+
+        builder.exec(allow_target_request).expect_success().commit();
+
+        let add_liquidity_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            legacy_pool_contract_hash,
+            "add_liquidity",
+            runtime_args! {
+                "amount" => U256::from(100i64),
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(add_liquidity_request)
+            .expect_success()
+            .commit();
+
+        let signer = "cde782dee9643b02dde8a11499ede81ec1d05dd3".to_string();
+        let add_signer_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            legacy_pool_contract_hash,
+            "add_signer",
+            runtime_args! {
+                "signer" => signer.clone(),
+            },
+        )
+        .build();
+
+        builder.exec(add_signer_request).expect_success().commit();
+
+        let migration_args = runtime_args! {
+            "token_addresses" => vec![erc20_contract_package_hash.to_formatted_string()],
+            "target_networks" => vec![U256::from(1i64)],
+            "providers" => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)],
+            "signers" => vec![signer.clone()],
+        };
+
+        // running the installer again adds a new version to the installed package
+        let mut upgrade_args = migration_args.clone();
+        upgrade_args
+            .insert("chain_name", CHAIN_NAME.to_string())
+            .unwrap();
+        let upgrade_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, BRIDGE_POOL_WASM, upgrade_args)
+                .build();
+
+        builder.exec(upgrade_request).expect_success().commit();
+
+        assert_eq!(
+            get_bridge_pool_contract_package_hash(&builder),
+            bridge_pool_contract_package_hash
+        );
+        let bridge_pool_contract_hash = get_bridge_pool_contract_hash(&builder);
+        assert_ne!(bridge_pool_contract_hash, legacy_pool_contract_hash);
+
+        // the previous version is disabled
+        let legacy_add_signer_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            legacy_pool_contract_hash,
+            "add_signer",
+            runtime_args! {
+                "signer" => signer.clone(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(legacy_add_signer_request)
+            .expect_failure()
+            .commit();
+
+        assert_eq!(
+            total_liquidity_dictionary(
+                &builder,
+                bridge_pool_contract_hash,
+                erc20_contract_package_hash,
+            ),
+            U256::from(100u64)
+        );
+        assert_eq!(
+            liquidity_providers_count(
+                &builder,
+                bridge_pool_contract_hash,
+                erc20_contract_package_hash,
+            ),
+            1
+        );
+
+        // entries already under typed keys are left as they are
+        let migrate_storage_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "migrate_storage",
+            migration_args,
+        )
+        .build();
+
+        builder
+            .exec(migrate_storage_request)
+            .expect_success()
+            .commit();
+
+        assert_eq!(
+            total_liquidity_dictionary(
+                &builder,
+                bridge_pool_contract_hash,
+                erc20_contract_package_hash,
+            ),
+            U256::from(100u64)
+        );
+
+        let check_signer_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "check_signer",
+            runtime_args! {
+                "signer" => signer,
+            },
+        )
+        .build();
+
+        builder.exec(check_signer_request).expect_success().commit();
+
+        let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "swap",
+            runtime_args! {
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+                "target_token" => "qwe".to_string(),
+                "target_address" => "qwe_addr".to_string(),
+                "target_network" => U256::from(1i64),
+                "amount" => U256::from(50i64),
+            },
+        )
+        .build();
+
+        builder.exec(swap_request).expect_success().commit();
+
+        let remove_liquidity_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            bridge_pool_contract_hash,
+            "remove_liquidity",
+            runtime_args! {
+                "amount" => U256::from(100i64),
+                "token_address" => erc20_contract_package_hash.to_formatted_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(remove_liquidity_request)
+            .expect_success()
+            .commit();
+
+        let total_liquidity = total_liquidity_dictionary(
+            &builder,
+            bridge_pool_contract_hash,
+            erc20_contract_package_hash,
+        );
+        assert_eq!(total_liquidity, U256::from(50u64));
+    }
+
+    #[test]
+    fn should_list_signers_tokens_and_liquidity_providers() {
+        let mut pool = install_pool();
+        approve_pool(&mut pool, U256::from(1000i64));
+        allow_target(&mut pool);
+        add_liquidity(&mut pool, U256::from(100i64));
+
+        assert_eq!(
+            liquidity_providers_count(
+                &pool.builder,
+                pool.bridge_pool_contract_hash,
+                pool.erc20_contract_package_hash,
+            ),
+            1
+        );
+
+        for signer in [
+            "cde782dee9643b02dde8a11499ede81ec1d05dd3",
+            "1ad5b8e5e0a5cc3d7a4e8b7e3c1f0ad3e4b6c9f2",
+        ] {
+            add_signer(&mut pool, signer.to_string());
+        }
+
+        call_bridge_pool(
+            &mut pool,
+            "remove_signer",
+            runtime_args! {
+                "signer" => "cde782dee9643b02dde8a11499ede81ec1d05dd3".to_string(),
+            },
+        )
+        .expect_success()
+        .commit();
+
+        let list_args = runtime_args! {
+            "offset" => 0u32,
+            "limit" => 10u32,
+        };
+        call_bridge_pool(&mut pool, "list_signers", list_args.clone())
+            .expect_success()
+            .commit();
+        call_bridge_pool(&mut pool, "list_tokens", list_args.clone())
+            .expect_success()
+            .commit();
+
+        let mut list_liquidity_providers_args = list_args;
+        list_liquidity_providers_args
+            .insert(
+                "token_address",
+                pool.erc20_contract_package_hash.to_formatted_string(),
+            )
+            .unwrap();
+        call_bridge_pool(
+            &mut pool,
+            "list_liquidity_providers",
+            list_liquidity_providers_args,
+        )
+        .expect_success()
+        .commit();
+
+        // a provider is no longer listed once all of its liquidity is removed
+        let args = remove_liquidity_args(&pool, U256::from(100i64));
+        call_bridge_pool(&mut pool, "remove_liquidity", args)
+            .expect_success()
+            .commit();
+
+        assert_eq!(
+            liquidity_providers_count(
+                &pool.builder,
+                pool.bridge_pool_contract_hash,
+                pool.erc20_contract_package_hash,
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn should_reject_claims_while_paused() {
+        let mut pool = install_pool_with_liquidity(U256::from(100i64));
+        allow_target(&mut pool);
+        let token_address = pool.erc20_contract_package_hash.to_formatted_string();
+
+        let expiry = 1_000_000u64;
+        let (message_hash, withdraw_signed_args, signer) = sign_withdrawal(
+            &pool,
+            U256::from(2i64),
+            "6b166cc8016d4ddb7a2578245ac9de73bd95f30ea960ab53dec02141623832dd",
+            expiry,
+        );
+        add_signer(&mut pool, signer);
+
+        call_bridge_pool(
+            &mut pool,
+            "set_withdrawal_delay",
+            runtime_args! {
+                "token_address" => token_address.clone(),
+                "threshold" => U256::one(),
+                "delay" => 1000u64,
+            },
+        )
+        .expect_success()
+        .commit();
+
+        let withdraw_signed_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            pool.bridge_pool_contract_hash,
+            "withdraw_signed",
+            withdraw_signed_args,
+        )
+        .with_block_time(expiry)
+        .build();
+
+        pool.builder
+            .exec(withdraw_signed_request)
+            .expect_success()
+            .commit();
+
+        // swap fees are shared with the liquidity providers
+        let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            pool.erc20_contract_hash,
+            "approve",
+            runtime_args! {
+                "spender" => Key::from(pool.bridge_pool_contract_package_hash),
+                "amount" => U256::from(200i64),
+            },
+        )
+        .build();
+
+        pool.builder.exec(approve_request).expect_success().commit();
+
+        for (entry_point, args) in [
+            (
+                "set_reward_share",
+                runtime_args! {
+                    "token_address" => token_address.clone(),
+                    "reward_share_bps" => 5000u32,
+                },
+            ),
+            (
+                "set_fee",
+                runtime_args! {
+                    "token_address" => token_address.clone(),
+                    "fee_bps" => 1000u32,
+                },
+            ),
+            (
+                "swap",
+                runtime_args! {
+                    "token_address" => token_address.clone(),
+                    "target_token" => "qwe".to_string(),
+                    "target_address" => "qwe_addr".to_string(),
+                    "target_network" => U256::from(1i64),
+                    "amount" => U256::from(200i64),
+                },
+            ),
+        ] {
+            call_bridge_pool(&mut pool, entry_point, args)
+                .expect_success()
+                .commit();
+        }
+
+        for operation in ["withdraw_signed", "claim_rewards"] {
+            call_bridge_pool(
+                &mut pool,
+                "pause",
+                runtime_args! {
+                    "operation" => operation.to_string(),
+                },
+            )
+            .expect_success()
+            .commit();
+        }
+
+        let claim_withdrawal_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            pool.bridge_pool_contract_hash,
+            "claim_withdrawal",
+            runtime_args! {
+                "message_hash" => message_hash.clone(),
+            },
+        )
+        .with_block_time(expiry + 1000)
+        .build();
+
+        pool.builder
+            .exec(claim_withdrawal_request)
+            .expect_failure()
+            .commit();
+
+        // nor can the pending withdrawal be cancelled
+        call_bridge_pool(
+            &mut pool,
+            "cancel_withdrawal",
+            runtime_args! {
+                "message_hash" => message_hash.clone(),
+            },
+        )
+        .expect_failure()
+        .commit();
+
+        call_bridge_pool(
+            &mut pool,
+            "claim_rewards",
+            runtime_args! {
+                "token_address" => token_address.clone(),
+            },
+        )
+        .expect_failure()
+        .commit();
+
+        // both can be claimed once the operations are unpaused
+        for operation in ["withdraw_signed", "claim_rewards"] {
+            call_bridge_pool(
+                &mut pool,
+                "unpause",
+                runtime_args! {
+                    "operation" => operation.to_string(),
+                },
+            )
+            .expect_success()
+            .commit();
+        }
+
+        let claim_withdrawal_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            pool.bridge_pool_contract_hash,
+            "claim_withdrawal",
+            runtime_args! {
+                "message_hash" => message_hash,
+            },
+        )
+        .with_block_time(expiry + 1000)
+        .build();
+
+        pool.builder
+            .exec(claim_withdrawal_request)
+            .expect_success()
+            .commit();
+
+        call_bridge_pool(
+            &mut pool,
+            "claim_rewards",
+            runtime_args! {
+                "token_address" => token_address,
+            },
+        )
+        .expect_success()
+        .commit();
+    }
+
+    /// Creates a dictionary item key for an (owner, spender) pair.
+    fn make_allowances_dictionary_item_key(owner: Key, spender: Key) -> String {
+        let mut preimage = Vec::new();
+        preimage.append(&mut owner.to_bytes().unwrap());
+        preimage.append(&mut spender.to_bytes().unwrap());
 
         let key_bytes = create_blake2b_hash(&preimage);
         hex::encode(&key_bytes)
@@ -1246,32 +4707,144 @@ mod tests {
         ContractPackageHash::new(bridge_pool_hash_addr)
     }
 
-    pub fn get_erc20_contract_package_hash(
+    pub fn get_erc20_contract_package_hash(
+        builder: &WasmTestBuilder<InMemoryGlobalState>,
+    ) -> ContractPackageHash {
+        let erc20_hash_addr = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get(ERC20_CONTRACT_PACKAGE_HASH)
+            .expect("must have this entry in named keys")
+            .into_hash()
+            .expect("must get hash_addr");
+
+        ContractPackageHash::new(erc20_hash_addr)
+    }
+
+    pub fn get_bridge_pool_contract_hash(
+        builder: &WasmTestBuilder<InMemoryGlobalState>,
+    ) -> ContractHash {
+        let bridge_pool_hash_addr = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get(BRIDGE_POOL_CONTRACT_HASH)
+            .expect("must have this entry in named keys")
+            .into_hash()
+            .expect("must get hash_addr");
+
+        ContractHash::new(bridge_pool_hash_addr)
+    }
+
+    pub fn get_bridge_caller_contract_package_hash(
+        builder: &WasmTestBuilder<InMemoryGlobalState>,
+    ) -> ContractPackageHash {
+        let bridge_caller_hash_addr = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get(BRIDGE_CALLER_CONTRACT_PACKAGE_HASH)
+            .expect("must have this entry in named keys")
+            .into_hash()
+            .expect("must get hash_addr");
+
+        ContractPackageHash::new(bridge_caller_hash_addr)
+    }
+
+    pub(crate) fn get_bridge_caller_contract_hash(
+        builder: &WasmTestBuilder<InMemoryGlobalState>,
+    ) -> ContractHash {
+        let bridge_caller_hash_addr = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get(BRIDGE_CALLER_CONTRACT_NAME)
+            .expect("must have this entry in named keys")
+            .into_hash()
+            .expect("must get hash_addr");
+
+        ContractHash::new(bridge_caller_hash_addr)
+    }
+
+    pub fn get_lp_token_contract_package_hash(
+        builder: &WasmTestBuilder<InMemoryGlobalState>,
+    ) -> ContractPackageHash {
+        let lp_token_hash_addr = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get(LP_TOKEN_CONTRACT_PACKAGE_HASH)
+            .expect("must have this entry in named keys")
+            .into_hash()
+            .expect("must get hash_addr");
+
+        ContractPackageHash::new(lp_token_hash_addr)
+    }
+
+    pub(crate) fn get_lp_token_contract_hash(
+        builder: &WasmTestBuilder<InMemoryGlobalState>,
+    ) -> ContractHash {
+        let lp_token_hash_addr = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get(LP_TOKEN_CONTRACT_NAME)
+            .expect("must have this entry in named keys")
+            .into_hash()
+            .expect("must get hash_addr");
+
+        ContractHash::new(lp_token_hash_addr)
+    }
+
+    pub fn get_cep18_token_contract_package_hash(
+        builder: &WasmTestBuilder<InMemoryGlobalState>,
+    ) -> ContractPackageHash {
+        let cep18_token_hash_addr = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get(CEP18_TOKEN_CONTRACT_PACKAGE_HASH)
+            .expect("must have this entry in named keys")
+            .into_hash()
+            .expect("must get hash_addr");
+
+        ContractPackageHash::new(cep18_token_hash_addr)
+    }
+
+    pub(crate) fn get_cep18_token_contract_hash(
+        builder: &WasmTestBuilder<InMemoryGlobalState>,
+    ) -> ContractHash {
+        let cep18_token_hash_addr = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get(CEP18_TOKEN_CONTRACT_NAME)
+            .expect("must have this entry in named keys")
+            .into_hash()
+            .expect("must get hash_addr");
+
+        ContractHash::new(cep18_token_hash_addr)
+    }
+
+    pub fn get_fee_token_contract_package_hash(
         builder: &WasmTestBuilder<InMemoryGlobalState>,
     ) -> ContractPackageHash {
-        let erc20_hash_addr = builder
+        let fee_token_hash_addr = builder
             .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
             .named_keys()
-            .get(ERC20_CONTRACT_PACKAGE_HASH)
+            .get(FEE_TOKEN_CONTRACT_PACKAGE_HASH)
             .expect("must have this entry in named keys")
             .into_hash()
             .expect("must get hash_addr");
 
-        ContractPackageHash::new(erc20_hash_addr)
+        ContractPackageHash::new(fee_token_hash_addr)
     }
 
-    pub fn get_bridge_pool_contract_hash(
+    pub(crate) fn get_fee_token_contract_hash(
         builder: &WasmTestBuilder<InMemoryGlobalState>,
     ) -> ContractHash {
-        let bridge_pool_hash_addr = builder
+        let fee_token_hash_addr = builder
             .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
             .named_keys()
-            .get(BRIDGE_POOL_CONTRACT_HASH)
+            .get(FEE_TOKEN_CONTRACT_NAME)
             .expect("must have this entry in named keys")
             .into_hash()
             .expect("must get hash_addr");
 
-        ContractHash::new(bridge_pool_hash_addr)
+        ContractHash::new(fee_token_hash_addr)
     }
 
     pub(crate) fn get_erc20_contract_hash(
@@ -1288,6 +4861,601 @@ mod tests {
         ContractHash::new(erc20_hash_addr)
     }
 
+    /// A bridge pool installed next to an ERC20 token minted to the default account.
+    struct TestPool {
+        builder: WasmTestBuilder<InMemoryGlobalState>,
+        erc20_contract_hash: ContractHash,
+        erc20_contract_package_hash: ContractPackageHash,
+        bridge_pool_contract_hash: ContractHash,
+        bridge_pool_contract_package_hash: ContractPackageHash,
+    }
+
+    /// Installs an ERC20 token minted to the default account and the bridge pool.
+    fn install_pool() -> TestPool {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let erc_20_install_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ERC20_WASM,
+            runtime_args! {
+                "name" => "FERRUM_ERC20".to_string(),
+                "symbol" => "F_ERC20".to_string(),
+                "chain_name" => CHAIN_NAME.to_string(),
+                "total_supply" => U256::from(500000i64),
+                "decimals" => 8u8,
+            },
+        )
+        .build();
+
+        builder
+            .exec(erc_20_install_request)
+            .expect_success()
+            .commit();
+
+        let erc20_contract_hash = get_erc20_contract_hash(&builder);
+        let erc20_contract_package_hash = get_erc20_contract_package_hash(&builder);
+
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract_hash,
+            "mint",
+            runtime_args! {},
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+
+        let contract_installation_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            BRIDGE_POOL_WASM,
+            runtime_args! {
+                "chain_name" => CHAIN_NAME.to_string(),
+            },
+        )
+        .build();
+
+        builder
+            .exec(contract_installation_request)
+            .expect_success()
+            .commit();
+
+        let bridge_pool_contract_package_hash = get_bridge_pool_contract_package_hash(&builder);
+        let bridge_pool_contract_hash = get_bridge_pool_contract_hash(&builder);
+
+        TestPool {
+            builder,
+            erc20_contract_hash,
+            erc20_contract_package_hash,
+            bridge_pool_contract_hash,
+            bridge_pool_contract_package_hash,
+        }
+    }
+
+    /// Approves the pool to spend `amount` of the token of the default account.
+    fn approve_pool(pool: &mut TestPool, amount: U256) {
+        let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            pool.erc20_contract_hash,
+            "approve",
+            runtime_args! {
+                "spender" => Key::from(pool.bridge_pool_contract_package_hash),
+                "amount" => amount,
+            },
+        )
+        .build();
+
+        pool.builder.exec(approve_request).expect_success().commit();
+    }
+
+    /// Registers the token of the pool and allows swapping it to `qwe` on network 1.
+    fn allow_target(pool: &mut TestPool) {
+        let args = runtime_args! {
+            "token_address" => pool.erc20_contract_package_hash.to_formatted_string(),
+            "token_name" => "some_unusual_token_name".to_string(),
+            "target_token" => "qwe".to_string(),
+            "target_network" => U256::from(1i64),
+        };
+        call_bridge_pool(pool, "allow_target", args)
+            .expect_success()
+            .commit();
+    }
+
+    /// Adds `amount` of the token of the pool from the default account, which must have
+    /// approved it.
+    fn add_liquidity(pool: &mut TestPool, amount: U256) {
+        let args = runtime_args! {
+            "amount" => amount,
+            "token_address" => pool.erc20_contract_package_hash.to_formatted_string(),
+            "bridge_pool_contract_package_hash" => pool.bridge_pool_contract_package_hash.to_formatted_string(),
+        };
+        call_bridge_pool(pool, "add_liquidity", args)
+            .expect_success()
+            .commit();
+    }
+
+    /// The arguments of the entry points only taking the token of the pool.
+    fn token_address_args(pool: &TestPool) -> RuntimeArgs {
+        runtime_args! {
+            "token_address" => pool.erc20_contract_package_hash.to_formatted_string(),
+        }
+    }
+
+    /// The arguments of a removal of `amount` of the token of the pool.
+    fn remove_liquidity_args(pool: &TestPool, amount: U256) -> RuntimeArgs {
+        runtime_args! {
+            "amount" => amount,
+            "token_address" => pool.erc20_contract_package_hash.to_formatted_string(),
+        }
+    }
+
+    /// The arguments of a swap of `amount` of the token of the pool to `qwe` on network 1.
+    fn swap_args(pool: &TestPool, amount: U256) -> RuntimeArgs {
+        runtime_args! {
+            "token_address" => pool.erc20_contract_package_hash.to_formatted_string(),
+            "target_token" => "qwe".to_string(),
+            "target_address" => "qwe_addr".to_string(),
+            "target_network" => U256::from(1i64),
+            "amount" => amount,
+        }
+    }
+
+    /// Installs an ERC20 token and the bridge pool, then adds `liquidity` of the token to the
+    /// pool from the default account.
+    fn install_pool_with_liquidity(liquidity: U256) -> TestPool {
+        let mut pool = install_pool();
+        approve_pool(&mut pool, liquidity);
+        add_liquidity(&mut pool, liquidity);
+        pool
+    }
+
+    /// Installs an LP token minted by the pool and registers it for the token of the pool,
+    /// returns its contract hash.
+    fn install_lp_token(pool: &mut TestPool) -> ContractHash {
+        let lp_token_installation_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            LP_TOKEN_WASM,
+            runtime_args! {
+                "name" => "FERRUM_ERC20_LP".to_string(),
+                "symbol" => "F_ERC20_LP".to_string(),
+                "chain_name" => CHAIN_NAME.to_string(),
+                "decimals" => 8u8,
+                "minter" => Key::from(pool.bridge_pool_contract_package_hash),
+            },
+        )
+        .build();
+
+        pool.builder
+            .exec(lp_token_installation_request)
+            .expect_success()
+            .commit();
+
+        let args = runtime_args! {
+            "token_address" => pool.erc20_contract_package_hash.to_formatted_string(),
+            "lp_token_address" => get_lp_token_contract_package_hash(&pool.builder).to_formatted_string(),
+        };
+        call_bridge_pool(pool, "set_lp_token", args)
+            .expect_success()
+            .commit();
+
+        get_lp_token_contract_hash(&pool.builder)
+    }
+
+    /// Signs a withdrawal of `amount` of the pool token to the default account with the test
+    /// signer key. Returns its message hash, the arguments of withdraw_signed and the signer.
+    fn sign_withdrawal(
+        pool: &TestPool,
+        amount: U256,
+        salt_string: &str,
+        expiry: u64,
+    ) -> (String, RuntimeArgs, String) {
+        sign_withdrawal_for(
+            pool,
+            pool.erc20_contract_package_hash,
+            (*DEFAULT_ACCOUNT_ADDR).to_string(),
+            amount,
+            salt_string,
+            expiry,
+        )
+    }
+
+    /// Signs a withdrawal of `amount` of `token` to `caller`, the hex of an account hash or of a
+    /// contract package hash, like sign_withdrawal.
+    fn sign_withdrawal_for(
+        pool: &TestPool,
+        token: ContractPackageHash,
+        caller: String,
+        amount: U256,
+        salt_string: &str,
+        expiry: u64,
+    ) -> (String, RuntimeArgs, String) {
+        let chain_id = 1u64;
+        let payee = "0Bdb79846e8331A19A65430363f240Ec8aCC2A52".to_string();
+        let token_recipient = "qwe".to_string();
+
+        let domain_separator = contract_utils::keccak::domain_separator(
+            pool.bridge_pool_contract_package_hash.value(),
+            CHAIN_NAME,
+            WITHDRAW_DOMAIN_VERSION,
+        );
+
+        let message_hash = contract_utils::keccak::message_hash(
+            domain_separator,
+            token.value(),
+            payee.clone(),
+            amount,
+            caller.clone(),
+            chain_id,
+            hex::decode(salt_string).unwrap().try_into().unwrap(),
+            token_recipient.clone(),
+            expiry,
+        );
+
+        let private_key_bytes =
+            hex::decode("a7a08a23f69090a53a32814da1d262c8d2728d16bce420ae143978d85a06be49")
+                .unwrap();
+        let message_hash_bytes = hex::decode(&message_hash).unwrap();
+        let signature = contract_utils::keccak::ecdsa_sign(&message_hash_bytes, &private_key_bytes);
+        let signer = hex::encode(
+            contract_utils::keccak::ecdsa_recover(
+                &message_hash_bytes,
+                &RecoverableSignature::from_bytes(&signature[..]).unwrap(),
+            )
+            .unwrap(),
+        );
+
+        let withdraw_signed_args = runtime_args! {
+            "token_address" => token.to_formatted_string(),
+            "payee" => payee,
+            "amount" => amount,
+            "chain_id" => chain_id,
+            "salt" => salt_string.to_string(),
+            "signatures" => vec![hex::encode(signature)],
+            "token_recipient" => token_recipient,
+            "caller" => caller,
+            "expiry" => expiry,
+        };
+
+        (message_hash, withdraw_signed_args, signer)
+    }
+
+    /// Groups the arguments of withdraw_signed into an item of withdraw_signed_batch.
+    #[allow(clippy::type_complexity)]
+    fn batch_withdrawal(
+        withdraw_signed_args: &RuntimeArgs,
+    ) -> (
+        (String, String, U256),
+        (u64, String, String),
+        (Vec<String>, String, u64),
+    ) {
+        fn arg<T: CLTyped + FromBytes>(args: &RuntimeArgs, name: &str) -> T {
+            args.get(name).unwrap().clone().into_t().unwrap()
+        }
+        let args = withdraw_signed_args;
+        (
+            (
+                arg(args, "token_address"),
+                arg(args, "payee"),
+                arg(args, "amount"),
+            ),
+            (
+                arg(args, "chain_id"),
+                arg(args, "salt"),
+                arg(args, "caller"),
+            ),
+            (
+                arg(args, "signatures"),
+                arg(args, "token_recipient"),
+                arg(args, "expiry"),
+            ),
+        )
+    }
+
+    /// Signs a relayed withdrawal of `amount` of the pool token to the default account, paying
+    /// `relayer_fee` of it to the submitter. Returns its message hash, the arguments of
+    /// withdraw_signed_relayed and the signer.
+    fn sign_relayed_withdrawal(
+        pool: &TestPool,
+        amount: U256,
+        relayer_fee: U256,
+        salt_string: &str,
+        expiry: u64,
+    ) -> (String, RuntimeArgs, String) {
+        let chain_id = 1u64;
+        let payee = (*DEFAULT_ACCOUNT_ADDR).to_formatted_string();
+
+        let domain_separator = contract_utils::keccak::domain_separator(
+            pool.bridge_pool_contract_package_hash.value(),
+            CHAIN_NAME,
+            WITHDRAW_DOMAIN_VERSION,
+        );
+
+        let message_hash = contract_utils::keccak::relayed_message_hash(
+            domain_separator,
+            pool.erc20_contract_package_hash.value(),
+            payee.clone(),
+            amount,
+            relayer_fee,
+            chain_id,
+            hex::decode(salt_string).unwrap().try_into().unwrap(),
+            expiry,
+        );
+
+        let private_key_bytes =
+            hex::decode("a7a08a23f69090a53a32814da1d262c8d2728d16bce420ae143978d85a06be49")
+                .unwrap();
+        let message_hash_bytes = hex::decode(&message_hash).unwrap();
+        let signature = contract_utils::keccak::ecdsa_sign(&message_hash_bytes, &private_key_bytes);
+        let signer = hex::encode(
+            contract_utils::keccak::ecdsa_recover(
+                &message_hash_bytes,
+                &RecoverableSignature::from_bytes(&signature[..]).unwrap(),
+            )
+            .unwrap(),
+        );
+
+        let withdraw_signed_relayed_args = runtime_args! {
+            "token_address" => pool.erc20_contract_package_hash.to_formatted_string(),
+            "payee" => payee,
+            "amount" => amount,
+            "relayer_fee" => relayer_fee,
+            "chain_id" => chain_id,
+            "salt" => salt_string.to_string(),
+            "signatures" => vec![hex::encode(signature)],
+            "expiry" => expiry,
+        };
+
+        (message_hash, withdraw_signed_relayed_args, signer)
+    }
+
+    /// Calls an entry point of any contract from the default account.
+    fn call_contract<'a>(
+        pool: &'a mut TestPool,
+        contract_hash: ContractHash,
+        entry_point: &str,
+        args: RuntimeArgs,
+    ) -> &'a mut WasmTestBuilder<InMemoryGlobalState> {
+        let request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            contract_hash,
+            entry_point,
+            args,
+        )
+        .build();
+
+        pool.builder.exec(request)
+    }
+
+    /// Calls an entry point of the pool from the default account.
+    fn call_bridge_pool<'a>(
+        pool: &'a mut TestPool,
+        entry_point: &str,
+        args: RuntimeArgs,
+    ) -> &'a mut WasmTestBuilder<InMemoryGlobalState> {
+        let request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            pool.bridge_pool_contract_hash,
+            entry_point,
+            args,
+        )
+        .build();
+
+        pool.builder.exec(request)
+    }
+
+    /// Calls an entry point of the pool from the default account at the given block time.
+    fn call_bridge_pool_at<'a>(
+        pool: &'a mut TestPool,
+        entry_point: &str,
+        args: RuntimeArgs,
+        block_time: u64,
+    ) -> &'a mut WasmTestBuilder<InMemoryGlobalState> {
+        call_bridge_pool_from(pool, *DEFAULT_ACCOUNT_ADDR, entry_point, args, block_time)
+    }
+
+    /// Calls an entry point of the pool from `caller` at the given block time.
+    fn call_bridge_pool_from<'a>(
+        pool: &'a mut TestPool,
+        caller: AccountHash,
+        entry_point: &str,
+        args: RuntimeArgs,
+        block_time: u64,
+    ) -> &'a mut WasmTestBuilder<InMemoryGlobalState> {
+        let request = ExecuteRequestBuilder::contract_call_by_hash(
+            caller,
+            pool.bridge_pool_contract_hash,
+            entry_point,
+            args,
+        )
+        .with_block_time(block_time)
+        .build();
+
+        pool.builder.exec(request)
+    }
+
+    /// Transfers CSPR from the default account to `account` so it can pay for deploys.
+    fn fund_account(pool: &mut TestPool, account: AccountHash) {
+        let fund_account_request = ExecuteRequestBuilder::transfer(
+            *DEFAULT_ACCOUNT_ADDR,
+            runtime_args! {
+                "target" => account,
+                "amount" => U512::from(100_000_000_000_000u64),
+                "id" => Option::<u64>::None,
+            },
+        )
+        .build();
+
+        pool.builder
+            .exec(fund_account_request)
+            .expect_success()
+            .commit();
+    }
+
+    fn add_signer(pool: &mut TestPool, signer: String) {
+        let add_signer_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            pool.bridge_pool_contract_hash,
+            "add_signer",
+            runtime_args! {
+                "signer" => signer,
+            },
+        )
+        .build();
+
+        pool.builder
+            .exec(add_signer_request)
+            .expect_success()
+            .commit();
+    }
+
+    fn get_pool_purse(
+        builder: &WasmTestBuilder<InMemoryGlobalState>,
+        bridge_pool_contract_hash: ContractHash,
+    ) -> URef {
+        builder
+            .query(None, bridge_pool_contract_hash.into(), &vec![])
+            .unwrap()
+            .as_contract()
+            .expect("must have bridge pool contract")
+            .named_keys()
+            .get("pool_purse")
+            .expect("must have pool_purse entry")
+            .into_uref()
+            .expect("must be a uref")
+    }
+
+    fn liquidity_providers_count(
+        builder: &WasmTestBuilder<InMemoryGlobalState>,
+        bridge_pool_contract_hash: ContractHash,
+        token_contract_package_hash: ContractPackageHash,
+    ) -> u32 {
+        let liquidity_providers_lengths_seed_uref = builder
+            .query(None, bridge_pool_contract_hash.into(), &vec![])
+            .unwrap()
+            .as_contract()
+            .expect("must have bridge pool contract")
+            .named_keys()
+            .get("liquidity_providers_dict_lengths")
+            .expect("must have liquidity_providers_dict_lengths entry")
+            .as_uref()
+            .expect("must be a uref")
+            .to_owned();
+
+        builder
+            .query_dictionary_item(
+                None,
+                liquidity_providers_lengths_seed_uref,
+                &hex::encode(create_blake2b_hash(
+                    token_contract_package_hash.to_bytes().unwrap(),
+                )),
+            )
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t()
+            .expect("must convert to u32")
+    }
+
+    /// Returns the (shares, reward debt, pending rewards) of a provider of the pool token.
+    fn provider_rewards_dictionary(pool: &TestPool, provider: Key) -> (U256, U256, U256) {
+        let provider_rewards_seed_uref = pool
+            .builder
+            .query(None, pool.bridge_pool_contract_hash.into(), &vec![])
+            .unwrap()
+            .as_contract()
+            .expect("must have bridge pool contract")
+            .named_keys()
+            .get("provider_rewards_dict")
+            .expect("must have provider_rewards_dict entry")
+            .as_uref()
+            .expect("must be a uref")
+            .to_owned();
+
+        let mut key_bytes = pool.erc20_contract_package_hash.to_bytes().unwrap();
+        key_bytes.append(&mut provider.to_bytes().unwrap());
+        pool.builder
+            .query_dictionary_item(
+                None,
+                provider_rewards_seed_uref,
+                &hex::encode(create_blake2b_hash(key_bytes)),
+            )
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t()
+            .expect("must convert to (U256, U256, U256)")
+    }
+
+    /// Returns the total reward shares of the pool token.
+    fn total_reward_shares(pool: &TestPool) -> U256 {
+        let rewards_seed_uref = pool
+            .builder
+            .query(None, pool.bridge_pool_contract_hash.into(), &vec![])
+            .unwrap()
+            .as_contract()
+            .expect("must have bridge pool contract")
+            .named_keys()
+            .get("rewards_dict")
+            .expect("must have rewards_dict entry")
+            .as_uref()
+            .expect("must be a uref")
+            .to_owned();
+
+        let (_, total_shares): (U256, U256) = pool
+            .builder
+            .query_dictionary_item(
+                None,
+                rewards_seed_uref,
+                &hex::encode(create_blake2b_hash(
+                    pool.erc20_contract_package_hash.to_bytes().unwrap(),
+                )),
+            )
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t()
+            .expect("must convert to (U256, U256)");
+        total_shares
+    }
+
+    fn total_liquidity_dictionary(
+        builder: &WasmTestBuilder<InMemoryGlobalState>,
+        bridge_pool_contract_hash: ContractHash,
+        token_contract_package_hash: ContractPackageHash,
+    ) -> U256 {
+        let total_liquidity_seed_uref = builder
+            .query(None, bridge_pool_contract_hash.into(), &vec![])
+            .unwrap()
+            .as_contract()
+            .expect("must have bridge pool contract")
+            .named_keys()
+            .get("total_liquidity_dict")
+            .expect("must have total_liquidity_dict entry")
+            .as_uref()
+            .expect("must be a uref")
+            .to_owned();
+
+        builder
+            .query_dictionary_item(
+                None,
+                total_liquidity_seed_uref,
+                &hex::encode(create_blake2b_hash(
+                    token_contract_package_hash.to_bytes().unwrap(),
+                )),
+            )
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t()
+            .expect("must convert to U256")
+    }
+
     fn balance_dictionary(
         builder: &WasmTestBuilder<InMemoryGlobalState>,
         erc20_contract_key: Key,
@@ -10,19 +10,28 @@ use casper_types::{
     ApiError, CLTyped, Key, URef,
 };
 
+use crate::host;
+
 pub struct Dict {
     uref: URef,
 }
 
 impl Dict {
     pub fn instance(name: &str) -> Dict {
-        let key = runtime::get_key(name).unwrap_or_revert();
+        let key = host::get_key(name).unwrap_or_revert();
         let uref = *key.as_uref().unwrap_or_revert();
         Dict { uref }
     }
 
     pub fn init(name: &str) {
-        storage::new_dictionary(name).unwrap_or_revert();
+        host::new_dictionary(name).unwrap_or_revert();
+    }
+
+    // creates the dictionary unless it already exists, e.g. when upgrading a contract
+    pub fn init_missing(name: &str) {
+        if !host::has_key(name) {
+            Self::init(name);
+        }
     }
 
     pub fn at(uref: URef) -> Dict {
@@ -30,7 +39,7 @@ impl Dict {
     }
 
     pub fn get<T: CLTyped + FromBytes>(&self, key: &str) -> Option<T> {
-        storage::dictionary_get(self.uref, key)
+        host::dictionary_get(self.uref, key)
             .unwrap_or_revert()
             .unwrap_or_default()
     }
@@ -44,7 +53,7 @@ impl Dict {
     }
 
     pub fn set<T: CLTyped + ToBytes>(&self, key: &str, value: T) {
-        storage::dictionary_put(self.uref, key, Some(value));
+        host::dictionary_put(self.uref, key, Some(value));
     }
 
     pub fn set_by_key<T: CLTyped + ToBytes>(&self, key: &Key, value: T) {
@@ -56,7 +65,7 @@ impl Dict {
     }
 
     pub fn remove<T: CLTyped + ToBytes>(&self, key: &str) {
-        storage::dictionary_put(self.uref, key, Option::<T>::None);
+        host::dictionary_put(self.uref, key, Option::<T>::None);
     }
 
     pub fn remove_by_key<T: CLTyped + ToBytes>(&self, key: &Key) {
@@ -66,6 +75,37 @@ impl Dict {
     pub fn remove_by_vec_of_keys<T: CLTyped + ToBytes>(&self, keys: (&Key, &Key)) {
         self.remove::<T>(&keys_to_str(keys.0, keys.1))
     }
+
+    pub fn get_typed<K: ToBytes + ?Sized, T: CLTyped + FromBytes>(&self, key: &K) -> Option<T> {
+        self.get(&typed_key_to_str(key))
+    }
+
+    pub fn set_typed<K: ToBytes + ?Sized, T: CLTyped + ToBytes>(&self, key: &K, value: T) {
+        self.set(&typed_key_to_str(key), value)
+    }
+
+    pub fn remove_typed<K: ToBytes + ?Sized, T: CLTyped + ToBytes>(&self, key: &K) {
+        self.remove::<T>(&typed_key_to_str(key))
+    }
+
+    // moves the value stored under a string key to a typed key, returns it when there was one
+    pub fn rekey<K: ToBytes + ?Sized, T: CLTyped + FromBytes + ToBytes + Clone>(
+        &self,
+        legacy_key: &str,
+        key: &K,
+    ) -> Option<T> {
+        let value = self.get::<T>(legacy_key)?;
+        self.set_typed(key, value.clone());
+        self.remove::<T>(legacy_key);
+        Some(value)
+    }
+}
+
+// typed keys are hashed from their bytesrepr serialization, so equal values always address the
+// same item whatever their string formatting, and composite keys are tuples
+pub fn typed_key_to_str<K: ToBytes + ?Sized>(key: &K) -> String {
+    let bytes = host::blake2b(key.to_bytes().unwrap_or_revert());
+    hex::encode(bytes)
 }
 
 pub fn key_to_str(key: &Key) -> String {
@@ -82,7 +122,7 @@ pub fn keys_to_str(key_a: &Key, key_b: &Key) -> String {
 
     bytes_a.append(&mut bytes_b);
 
-    let bytes = runtime::blake2b(bytes_a);
+    let bytes = host::blake2b(bytes_a);
     hex::encode(bytes)
 }
 
@@ -92,7 +132,7 @@ pub fn key_and_value_to_str<T: CLTyped + ToBytes>(key: &Key, value: &T) -> Strin
 
     bytes_a.append(&mut bytes_b);
 
-    let bytes = runtime::blake2b(bytes_a);
+    let bytes = host::blake2b(bytes_a);
     hex::encode(bytes)
 }
 
@@ -119,3 +159,28 @@ pub fn set_key<T: ToBytes + CLTyped>(name: &str, value: T) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use casper_types::{ContractPackageHash, U256};
+
+    use super::typed_key_to_str;
+
+    #[test]
+    fn typed_key_to_str_is_deterministic() {
+        let key = (ContractPackageHash::new([1u8; 32]), U256::from(7u64));
+        let same_key = (ContractPackageHash::new([1u8; 32]), U256::from(7u64));
+        assert_eq!(typed_key_to_str(&key), typed_key_to_str(&same_key));
+        assert_eq!(typed_key_to_str(&key).len(), 64);
+        assert_eq!(
+            typed_key_to_str("token"),
+            typed_key_to_str(&String::from("token"))
+        );
+        assert_ne!(
+            typed_key_to_str(&key),
+            typed_key_to_str(&(ContractPackageHash::new([1u8; 32]), U256::from(8u64)))
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    CLTyped,
+};
+
+use crate::{Dict, NestedDict, TypedDict};
+
+const KEYS_SUFFIX: &str = "_keys";
+const LENGTHS_SUFFIX: &str = "_lengths";
+const INDEXES_SUFFIX: &str = "_indexes";
+
+// a NestedDict whose inner keys can be listed per outer key, the inner keys are kept in an
+// index -> key dictionary, their count in a dictionary of its own, and a key -> index dictionary to remove them in O(1) by
+// moving the last key into the place of the removed one
+pub struct EnumerableNestedDict<K1, K2, V> {
+    values: NestedDict<K1, K2, V>,
+    keys: NestedDict<K1, u32, K2>,
+    lengths: TypedDict<K1, u32>,
+    indexes: NestedDict<K1, K2, u32>,
+}
+
+impl<K1, K2, V> EnumerableNestedDict<K1, K2, V>
+where
+    K1: ToBytes,
+    K2: CLTyped + FromBytes + ToBytes + Clone,
+    V: CLTyped + FromBytes + ToBytes,
+{
+    pub fn instance(name: &str) -> Self {
+        EnumerableNestedDict {
+            values: NestedDict::instance(name),
+            keys: NestedDict::instance(&keys_dict_name(name)),
+            lengths: TypedDict::instance(&lengths_dict_name(name)),
+            indexes: NestedDict::instance(&indexes_dict_name(name)),
+        }
+    }
+
+    pub fn init(name: &str) {
+        Dict::init(name);
+        Dict::init(&keys_dict_name(name));
+        Dict::init(&lengths_dict_name(name));
+        Dict::init(&indexes_dict_name(name));
+    }
+
+    pub fn init_missing(name: &str) {
+        Dict::init_missing(name);
+        Dict::init_missing(&keys_dict_name(name));
+        Dict::init_missing(&lengths_dict_name(name));
+        Dict::init_missing(&indexes_dict_name(name));
+    }
+
+    // the untyped dictionary of the values, to read entries written under string keys
+    pub fn as_dict(&self) -> &Dict {
+        self.values.as_dict()
+    }
+
+    pub fn len(&self, outer_key: &K1) -> u32 {
+        self.lengths.get_or_default(outer_key)
+    }
+
+    pub fn is_empty(&self, outer_key: &K1) -> bool {
+        self.len(outer_key) == 0
+    }
+
+    pub fn get(&self, outer_key: &K1, inner_key: &K2) -> Option<V> {
+        self.values.get(outer_key, inner_key)
+    }
+
+    pub fn contains(&self, outer_key: &K1, inner_key: &K2) -> bool {
+        self.indexes.contains(outer_key, inner_key)
+    }
+
+    pub fn set(&self, outer_key: &K1, inner_key: &K2, value: V) {
+        if !self.contains(outer_key, inner_key) {
+            let length = self.len(outer_key);
+            self.keys.set(outer_key, &length, inner_key.clone());
+            self.indexes.set(outer_key, inner_key, length);
+            self.lengths.set(outer_key, length + 1);
+        }
+        self.values.set(outer_key, inner_key, value);
+    }
+
+    pub fn remove(&self, outer_key: &K1, inner_key: &K2) {
+        if let Some(index) = self.indexes.take(outer_key, inner_key) {
+            let last_index = self.len(outer_key) - 1;
+            let last_key = self.keys.take(outer_key, &last_index).unwrap_or_revert();
+            if index != last_index {
+                self.indexes.set(outer_key, &last_key, index);
+                self.keys.set(outer_key, &index, last_key);
+            }
+            self.lengths.set(outer_key, last_index);
+            self.values.remove(outer_key, inner_key);
+        }
+    }
+
+    // at most `limit` inner keys of an outer key starting at the `offset`th one, the order changes
+    // when keys are removed
+    pub fn keys(&self, outer_key: &K1, offset: u32, limit: u32) -> Vec<K2> {
+        let end = self.len(outer_key).min(offset.saturating_add(limit));
+        (offset..end)
+            .map(|index| self.keys.get(outer_key, &index).unwrap_or_revert())
+            .collect()
+    }
+
+    // moves the value stored under a string key to its pair of keys and lists it, returns it when
+    // there was one
+    pub fn rekey(&self, legacy_key: &str, outer_key: &K1, inner_key: &K2) -> Option<V>
+    where
+        V: Clone,
+    {
+        let value = self.as_dict().get::<V>(legacy_key)?;
+        self.as_dict().remove::<V>(legacy_key);
+        self.set(outer_key, inner_key, value.clone());
+        Some(value)
+    }
+}
+
+// a TypedDict whose keys can be listed, its values live under the same item keys as in a TypedDict
+// of the same name
+pub struct EnumerableDict<K, V> {
+    dict: EnumerableNestedDict<(), K, V>,
+}
+
+impl<K, V> EnumerableDict<K, V>
+where
+    K: CLTyped + FromBytes + ToBytes + Clone,
+    V: CLTyped + FromBytes + ToBytes,
+{
+    pub fn instance(name: &str) -> Self {
+        EnumerableDict {
+            dict: EnumerableNestedDict::instance(name),
+        }
+    }
+
+    pub fn init(name: &str) {
+        EnumerableNestedDict::<(), K, V>::init(name);
+    }
+
+    pub fn init_missing(name: &str) {
+        EnumerableNestedDict::<(), K, V>::init_missing(name);
+    }
+
+    // the untyped dictionary of the values, to read entries written under string keys
+    pub fn as_dict(&self) -> &Dict {
+        self.dict.as_dict()
+    }
+
+    pub fn len(&self) -> u32 {
+        self.dict.len(&())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dict.is_empty(&())
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.dict.get(&(), key)
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.dict.contains(&(), key)
+    }
+
+    pub fn set(&self, key: &K, value: V) {
+        self.dict.set(&(), key, value);
+    }
+
+    pub fn remove(&self, key: &K) {
+        self.dict.remove(&(), key);
+    }
+
+    // at most `limit` keys starting at the `offset`th one, the order changes when keys are removed
+    pub fn keys(&self, offset: u32, limit: u32) -> Vec<K> {
+        self.dict.keys(&(), offset, limit)
+    }
+
+    // moves the value stored under a string key to its typed key and lists it, returns it when
+    // there was one
+    pub fn rekey(&self, legacy_key: &str, key: &K) -> Option<V>
+    where
+        V: Clone,
+    {
+        self.dict.rekey(legacy_key, &(), key)
+    }
+}
+
+fn keys_dict_name(name: &str) -> String {
+    String::from(name) + KEYS_SUFFIX
+}
+
+fn lengths_dict_name(name: &str) -> String {
+    String::from(name) + LENGTHS_SUFFIX
+}
+
+fn indexes_dict_name(name: &str) -> String {
+    String::from(name) + INDEXES_SUFFIX
+}
@@ -0,0 +1,95 @@
+// the host functions the dictionaries are built on, an in-memory host stands in for them in the
+// unit tests of this crate
+#[cfg(not(test))]
+pub use casper_contract::contract_api::{
+    runtime::{blake2b, get_key, has_key},
+    storage::{dictionary_get, dictionary_put, new_dictionary},
+};
+
+#[cfg(test)]
+pub use in_memory::{blake2b, dictionary_get, dictionary_put, get_key, has_key, new_dictionary};
+
+#[cfg(test)]
+mod in_memory {
+    use alloc::{collections::BTreeMap, string::String, vec::Vec};
+    use core::cell::RefCell;
+
+    use blake2::{
+        digest::{Update, VariableOutput},
+        VarBlake2b,
+    };
+    use casper_types::{
+        bytesrepr::{self, FromBytes, ToBytes},
+        AccessRights, ApiError, CLTyped, Key, URef, BLAKE2B_DIGEST_LENGTH,
+    };
+
+    // named keys of the contract and the items of its dictionaries, every test runs in a thread
+    // of its own and so starts from an empty contract
+    #[derive(Default)]
+    struct Host {
+        named_keys: BTreeMap<String, Key>,
+        items: BTreeMap<([u8; 32], String), Vec<u8>>,
+    }
+
+    std::thread_local! {
+        static HOST: RefCell<Host> = RefCell::new(Host::default());
+    }
+
+    pub fn blake2b<T: AsRef<[u8]>>(input: T) -> [u8; BLAKE2B_DIGEST_LENGTH] {
+        let mut result = [0; BLAKE2B_DIGEST_LENGTH];
+        let mut hasher = VarBlake2b::new(BLAKE2B_DIGEST_LENGTH).expect("should create hasher");
+        hasher.update(input);
+        hasher.finalize_variable(|slice| result.copy_from_slice(slice));
+        result
+    }
+
+    pub fn get_key(name: &str) -> Option<Key> {
+        HOST.with(|host| host.borrow().named_keys.get(name).copied())
+    }
+
+    pub fn has_key(name: &str) -> bool {
+        get_key(name).is_some()
+    }
+
+    pub fn new_dictionary(name: &str) -> Result<URef, ApiError> {
+        if has_key(name) {
+            return Err(ApiError::DuplicateKey);
+        }
+        let uref = URef::new(blake2b(name), AccessRights::READ_ADD_WRITE);
+        HOST.with(|host| {
+            host.borrow_mut()
+                .named_keys
+                .insert(String::from(name), Key::URef(uref))
+        });
+        Ok(uref)
+    }
+
+    pub fn dictionary_get<V: CLTyped + FromBytes>(
+        uref: URef,
+        key: &str,
+    ) -> Result<Option<V>, ApiError> {
+        HOST.with(|host| {
+            host.borrow()
+                .items
+                .get(&(uref.addr(), String::from(key)))
+                .map(|bytes| bytesrepr::deserialize(bytes.clone()))
+                .transpose()
+                .map_err(ApiError::from)
+        })
+    }
+
+    pub fn dictionary_put<V: CLTyped + ToBytes>(uref: URef, key: &str, value: V) {
+        let bytes = value.into_bytes().expect("should serialize");
+        HOST.with(|host| {
+            host.borrow_mut()
+                .items
+                .insert((uref.addr(), String::from(key)), bytes)
+        });
+    }
+
+    // reverting aborts the test instead of the contract
+    #[no_mangle]
+    extern "C" fn casper_revert(status: u32) -> ! {
+        panic!("reverted with status {}", status)
+    }
+}
@@ -1,6 +1,7 @@
 use core::convert::TryInto;
 
 use alloc::{string::String, vec::Vec};
+use casper_types::U256;
 use k256::ecdsa::recoverable::Signature as RecoverableSignature;
 use secp256k1::{Message, Secp256k1, SecretKey};
 use tiny_keccak::{Hasher, Keccak};
@@ -40,26 +41,218 @@ pub fn keccak256_hash(bytes: &[u8]) -> Vec<u8> {
     resp.iter().cloned().collect()
 }
 
+// EIP-712 type strings of the withdrawal domain and of the signed withdrawal itself
+pub const WITHDRAW_DOMAIN_TYPE: &str =
+    "EIP712Domain(string version,string chainName,bytes32 verifyingContract)";
+pub const WITHDRAW_TYPE: &str = "Withdraw(bytes32 token,string payee,uint256 amount,string tokenRecipient,string caller,uint64 chainId,uint64 expiry,bytes32 salt)";
+// EIP-712 type string of a withdrawal any account can submit, paying `relayerFee` to it
+pub const RELAYED_WITHDRAW_TYPE: &str = "RelayedWithdraw(bytes32 token,string payee,uint256 amount,uint256 relayerFee,uint64 chainId,uint64 expiry,bytes32 salt)";
+
+// domain separator binding a signature to one bridge pool deployment on one Casper network
+pub fn domain_separator(
+    bridge_pool_contract_package_hash: [u8; 32],
+    chain_name: &str,
+    version: &str,
+) -> [u8; 32] {
+    keccak256(
+        &[
+            &keccak256(WITHDRAW_DOMAIN_TYPE.as_bytes())[..],
+            &keccak256(version.as_bytes()),
+            &keccak256(chain_name.as_bytes()),
+            &bridge_pool_contract_package_hash,
+        ]
+        .concat()[..],
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn withdraw_struct_hash(
+    token_contract_package_hash: [u8; 32],
+    payee: &str,
+    amount: U256,
+    token_recipient: &str,
+    caller: &str,
+    chain_id: u64,
+    expiry: u64,
+    salt: [u8; 32],
+) -> [u8; 32] {
+    keccak256(
+        &[
+            &keccak256(WITHDRAW_TYPE.as_bytes())[..],
+            &token_contract_package_hash,
+            &keccak256(payee.as_bytes()),
+            &encode_u256(amount),
+            &keccak256(token_recipient.as_bytes()),
+            &keccak256(caller.as_bytes()),
+            &encode_u256(U256::from(chain_id)),
+            &encode_u256(U256::from(expiry)),
+            &salt,
+        ]
+        .concat()[..],
+    )
+}
+
+// hex encoded keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(withdraw)), the digest signers sign
+#[allow(clippy::too_many_arguments)]
 pub fn message_hash(
-    token_contract_package_hash: String,
+    domain_separator: [u8; 32],
+    token_contract_package_hash: [u8; 32],
     payee: String,
-    amount: String,
+    amount: U256,
     caller: String,
     chain_id: u64,
     salt: [u8; 32],
     token_recipient: String,
+    expiry: u64,
+) -> String {
+    let struct_hash = withdraw_struct_hash(
+        token_contract_package_hash,
+        &payee,
+        amount,
+        &token_recipient,
+        &caller,
+        chain_id,
+        expiry,
+        salt,
+    );
+
+    hex::encode(keccak256(
+        &[&[0x19u8, 0x01u8][..], &domain_separator, &struct_hash].concat()[..],
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn relayed_withdraw_struct_hash(
+    token_contract_package_hash: [u8; 32],
+    payee: &str,
+    amount: U256,
+    relayer_fee: U256,
+    chain_id: u64,
+    expiry: u64,
+    salt: [u8; 32],
+) -> [u8; 32] {
+    keccak256(
+        &[
+            &keccak256(RELAYED_WITHDRAW_TYPE.as_bytes())[..],
+            &token_contract_package_hash,
+            &keccak256(payee.as_bytes()),
+            &encode_u256(amount),
+            &encode_u256(relayer_fee),
+            &encode_u256(U256::from(chain_id)),
+            &encode_u256(U256::from(expiry)),
+            &salt,
+        ]
+        .concat()[..],
+    )
+}
+
+// hex encoded keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(relayedWithdraw))
+#[allow(clippy::too_many_arguments)]
+pub fn relayed_message_hash(
+    domain_separator: [u8; 32],
+    token_contract_package_hash: [u8; 32],
+    payee: String,
+    amount: U256,
+    relayer_fee: U256,
+    chain_id: u64,
+    salt: [u8; 32],
+    expiry: u64,
 ) -> String {
+    let struct_hash = relayed_withdraw_struct_hash(
+        token_contract_package_hash,
+        &payee,
+        amount,
+        relayer_fee,
+        chain_id,
+        expiry,
+        salt,
+    );
+
+    hex::encode(keccak256(
+        &[&[0x19u8, 0x01u8][..], &domain_separator, &struct_hash].concat()[..],
+    ))
+}
+
+// EIP-712 type strings of the token permit domain and of the permit itself, where owner and
+// spender are the bytesrepr serialized `Key`s of the two addresses
+pub const PERMIT_DOMAIN_TYPE: &str =
+    "EIP712Domain(string name,string version,string chainName,bytes32 verifyingContract)";
+pub const PERMIT_TYPE: &str =
+    "Permit(bytes owner,bytes spender,uint256 value,uint256 nonce,uint64 deadline)";
+
+// domain separator binding a permit to one token contract package on one chain
+pub fn permit_domain_separator(
+    token_contract_package_hash: [u8; 32],
+    name: &str,
+    version: &str,
+    chain_name: &str,
+) -> [u8; 32] {
+    keccak256(
+        &[
+            &keccak256(PERMIT_DOMAIN_TYPE.as_bytes())[..],
+            &keccak256(name.as_bytes()),
+            &keccak256(version.as_bytes()),
+            &keccak256(chain_name.as_bytes()),
+            &token_contract_package_hash,
+        ]
+        .concat()[..],
+    )
+}
+
+pub fn permit_struct_hash(
+    owner: &[u8],
+    spender: &[u8],
+    value: U256,
+    nonce: U256,
+    deadline: u64,
+) -> [u8; 32] {
+    keccak256(
+        &[
+            &keccak256(PERMIT_TYPE.as_bytes())[..],
+            &keccak256(owner),
+            &keccak256(spender),
+            &encode_u256(value),
+            &encode_u256(nonce),
+            &encode_u256(U256::from(deadline)),
+        ]
+        .concat()[..],
+    )
+}
+
+// keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(permit)), the digest the owner signs
+pub fn permit_message_hash(
+    domain_separator: [u8; 32],
+    owner: &[u8],
+    spender: &[u8],
+    value: U256,
+    nonce: U256,
+    deadline: u64,
+) -> [u8; 32] {
+    let struct_hash = permit_struct_hash(owner, spender, value, nonce, deadline);
+
+    keccak256(&[&[0x19u8, 0x01u8][..], &domain_separator, &struct_hash].concat()[..])
+}
+
+// compressed public key of the signer of `hash`, from a 65 byte r ‖ s ‖ v signature as returned
+// by `ecdsa_sign`
+pub fn ecdsa_recover_public_key(
+    hash: &[u8],
+    signature: &[u8],
+) -> Result<[u8; 33], secp256k1::Error> {
+    if signature.len() != 65 {
+        return Err(secp256k1::Error::InvalidSignature);
+    }
+    let msg = Message::from_slice(hash)?;
+    let recovery_id = secp256k1::ecdsa::RecoveryId::from_i32(i32::from(signature[64]))?;
+    let sig = secp256k1::ecdsa::RecoverableSignature::from_compact(&signature[..64], recovery_id)?;
+    let pub_key = Secp256k1::new().recover_ecdsa(&msg, &sig)?;
+    Ok(pub_key.serialize())
+}
 
-    hex::encode(keccak256(&hex::encode(keccak256(&[
-        token_contract_package_hash.as_bytes(),
-        payee.as_bytes(),
-        amount.as_bytes(),
-        token_recipient.as_bytes(),
-        caller.as_bytes(),
-        &chain_id.to_be_bytes(),
-        &salt,
-    ]
-    .concat()[..])).as_bytes()))
+fn encode_u256(value: U256) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    value.to_big_endian(&mut bytes);
+    bytes
 }
 
 pub fn ecdsa_sign(hash: &[u8], private_key: &[u8]) -> [u8; 65] {
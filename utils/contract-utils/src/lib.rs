@@ -2,14 +2,21 @@
 #![feature(once_cell)]
 
 extern crate alloc;
+#[cfg(test)]
+extern crate std;
 
 mod admin_control;
 mod contract_context;
 mod contract_storage;
 mod data;
+mod enumerable_dict;
+mod host;
 pub mod keccak;
+mod typed_dict;
 
 pub use admin_control::AdminControl;
 pub use contract_context::ContractContext;
 pub use contract_storage::{ContractStorage, OnChainContractStorage};
-pub use data::{get_key, key_and_value_to_str, key_to_str, set_key, Dict};
+pub use data::{get_key, key_and_value_to_str, key_to_str, set_key, typed_key_to_str, Dict};
+pub use enumerable_dict::{EnumerableDict, EnumerableNestedDict};
+pub use typed_dict::{NestedDict, TypedDict};
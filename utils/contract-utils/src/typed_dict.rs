@@ -0,0 +1,235 @@
+use alloc::{string::String, vec::Vec};
+use core::{borrow::Borrow, marker::PhantomData};
+
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    CLTyped,
+};
+
+use crate::{host, Dict};
+
+// a dictionary holding values of type `V` under typed keys of type `K`, give every one a named
+// dictionary of its own as the keys of a NestedDict<K1, K2> collide with those of a TypedDict<K>
+// whenever `K` serializes as `K1` followed by `K2`
+pub struct TypedDict<K, V> {
+    dict: Dict,
+    marker: PhantomData<(K, V)>,
+}
+
+impl<K: ToBytes, V: CLTyped + FromBytes + ToBytes> TypedDict<K, V> {
+    pub fn instance(name: &str) -> Self {
+        TypedDict {
+            dict: Dict::instance(name),
+            marker: PhantomData,
+        }
+    }
+
+    pub fn init(name: &str) {
+        Dict::init(name);
+    }
+
+    // the untyped dictionary, to read entries written under string keys
+    pub fn as_dict(&self) -> &Dict {
+        &self.dict
+    }
+
+    pub fn get<Q: ToBytes + ?Sized>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.dict.get_typed(key)
+    }
+
+    pub fn get_or_default<Q: ToBytes + ?Sized>(&self, key: &Q) -> V
+    where
+        K: Borrow<Q>,
+        V: Default,
+    {
+        self.get(key).unwrap_or_default()
+    }
+
+    pub fn contains<Q: ToBytes + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.get(key).is_some()
+    }
+
+    pub fn set<Q: ToBytes + ?Sized>(&self, key: &Q, value: V)
+    where
+        K: Borrow<Q>,
+    {
+        self.dict.set_typed(key, value);
+    }
+
+    pub fn remove<Q: ToBytes + ?Sized>(&self, key: &Q)
+    where
+        K: Borrow<Q>,
+    {
+        self.dict.remove_typed::<_, V>(key);
+    }
+
+    // removes the value and returns it when there was one
+    pub fn take<Q: ToBytes + ?Sized>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        let value = self.get(key)?;
+        self.remove(key);
+        Some(value)
+    }
+
+    // replaces the value, or the default one when there is none, with `f` of it and returns it
+    pub fn update_with<Q: ToBytes + ?Sized, F: FnOnce(V) -> V>(&self, key: &Q, f: F) -> V
+    where
+        K: Borrow<Q>,
+        V: Default + Clone,
+    {
+        let value = f(self.get_or_default(key));
+        self.set(key, value.clone());
+        value
+    }
+
+    // moves the value stored under a string key to its typed key, returns it when there was one
+    pub fn rekey<Q: ToBytes + ?Sized>(&self, legacy_key: &str, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        V: Clone,
+    {
+        self.dict.rekey(legacy_key, key)
+    }
+}
+
+// a dictionary holding values of type `V` under pairs of keys, e.g. token -> client -> amount,
+// stored under the hash of the (K1, K2) tuple instead of a dictionary per outer key
+pub struct NestedDict<K1, K2, V> {
+    dict: Dict,
+    marker: PhantomData<(K1, K2, V)>,
+}
+
+impl<K1: ToBytes, K2: ToBytes, V: CLTyped + FromBytes + ToBytes> NestedDict<K1, K2, V> {
+    pub fn instance(name: &str) -> Self {
+        NestedDict {
+            dict: Dict::instance(name),
+            marker: PhantomData,
+        }
+    }
+
+    pub fn init(name: &str) {
+        Dict::init(name);
+    }
+
+    // the untyped dictionary, to read entries written under string keys
+    pub fn as_dict(&self) -> &Dict {
+        &self.dict
+    }
+
+    pub fn get(&self, outer_key: &K1, inner_key: &K2) -> Option<V> {
+        self.dict.get(&nested_key_to_str(outer_key, inner_key))
+    }
+
+    pub fn get_or_default(&self, outer_key: &K1, inner_key: &K2) -> V
+    where
+        V: Default,
+    {
+        self.get(outer_key, inner_key).unwrap_or_default()
+    }
+
+    pub fn contains(&self, outer_key: &K1, inner_key: &K2) -> bool {
+        self.get(outer_key, inner_key).is_some()
+    }
+
+    pub fn set(&self, outer_key: &K1, inner_key: &K2, value: V) {
+        self.dict
+            .set(&nested_key_to_str(outer_key, inner_key), value);
+    }
+
+    pub fn remove(&self, outer_key: &K1, inner_key: &K2) {
+        self.dict
+            .remove::<V>(&nested_key_to_str(outer_key, inner_key));
+    }
+
+    // removes the value and returns it when there was one
+    pub fn take(&self, outer_key: &K1, inner_key: &K2) -> Option<V> {
+        let value = self.get(outer_key, inner_key)?;
+        self.remove(outer_key, inner_key);
+        Some(value)
+    }
+
+    // replaces the value, or the default one when there is none, with `f` of it and returns it
+    pub fn update_with<F: FnOnce(V) -> V>(&self, outer_key: &K1, inner_key: &K2, f: F) -> V
+    where
+        V: Default + Clone,
+    {
+        let value = f(self.get_or_default(outer_key, inner_key));
+        self.set(outer_key, inner_key, value.clone());
+        value
+    }
+}
+
+// the bytesrepr of a tuple is the concatenation of its fields, so this is the same item key as
+// `typed_key_to_str(&(outer_key, inner_key))`
+fn nested_key_to_str<K1: ToBytes, K2: ToBytes>(outer_key: &K1, inner_key: &K2) -> String {
+    let mut bytes: Vec<u8> = outer_key.to_bytes().unwrap_or_revert();
+    bytes.append(&mut inner_key.to_bytes().unwrap_or_revert());
+    hex::encode(host::blake2b(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use super::{nested_key_to_str, NestedDict, TypedDict};
+
+    #[test]
+    fn nested_keys_do_not_collide_across_splits() {
+        let (ab, c) = (String::from("ab"), String::from("c"));
+        let (a, bc) = (String::from("a"), String::from("bc"));
+        assert_ne!(nested_key_to_str(&ab, &c), nested_key_to_str(&a, &bc));
+
+        NestedDict::<String, String, u32>::init("nested");
+        let dict = NestedDict::<String, String, u32>::instance("nested");
+        dict.set(&ab, &c, 1);
+        dict.set(&a, &bc, 2);
+        assert_eq!(dict.get(&ab, &c), Some(1));
+        assert_eq!(dict.get(&a, &bc), Some(2));
+        assert_eq!(dict.get(&String::from("abc"), &String::new()), None);
+    }
+
+    #[test]
+    fn take_removes_the_value_and_returns_it() {
+        TypedDict::<String, u32>::init("typed");
+        let dict = TypedDict::<String, u32>::instance("typed");
+        let key = String::from("key");
+        assert_eq!(dict.take(&key), None);
+        dict.set(&key, 3);
+        assert_eq!(dict.take(&key), Some(3));
+        assert_eq!(dict.take(&key), None);
+        assert!(!dict.contains(&key));
+
+        NestedDict::<String, String, u32>::init("nested");
+        let dict = NestedDict::<String, String, u32>::instance("nested");
+        let inner_key = String::from("inner_key");
+        dict.set(&key, &inner_key, 4);
+        assert_eq!(dict.take(&key, &inner_key), Some(4));
+        assert_eq!(dict.take(&key, &inner_key), None);
+    }
+
+    #[test]
+    fn get_or_default_returns_the_default_value_of_missing_keys() {
+        TypedDict::<String, u32>::init("typed");
+        let dict = TypedDict::<String, u32>::instance("typed");
+        let key = String::from("key");
+        assert_eq!(dict.get_or_default(&key), 0);
+        dict.set(&key, 3);
+        assert_eq!(dict.get_or_default(&key), 3);
+        dict.remove(&key);
+        assert_eq!(dict.get_or_default(&key), 0);
+
+        // a default value that was set is still a value
+        dict.set(&key, 0);
+        assert!(dict.contains(&key));
+        assert_eq!(dict.get_or_default(&key), 0);
+    }
+}